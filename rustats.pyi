@@ -1,43 +1,415 @@
+from typing import Literal, TypeVar
+
 import numpy as np
 from numpy.typing import NDArray
 
+Float = TypeVar("Float", np.float32, np.float64)
+Interpolation = Literal["linear", "lower", "higher", "nearest", "midpoint"]
+WinType = Literal["boxcar", "triang", "bartlett", "hann", "hamming", "blackman", "gaussian"]
+
 def move_sum(
-    array: NDArray[np.float64], length: int, min_length: int, parallel: bool
-) -> NDArray[np.float64]: ...
+    array: NDArray[Float],
+    length: int,
+    min_length: int,
+    parallel: bool,
+    axis: int = 0,
+    center: bool = False,
+) -> NDArray[Float]: ...
 def move_std(
-    array: NDArray[np.float64], length: int, min_length: int, parallel: bool
-) -> NDArray[np.float64]: ...
+    array: NDArray[Float],
+    length: int,
+    min_length: int,
+    parallel: bool,
+    axis: int = 0,
+    center: bool = False,
+) -> NDArray[Float]: ...
 def move_var(
-    array: NDArray[np.float64], length: int, min_length: int, parallel: bool
-) -> NDArray[np.float64]: ...
+    array: NDArray[Float],
+    length: int,
+    min_length: int,
+    parallel: bool,
+    axis: int = 0,
+    center: bool = False,
+) -> NDArray[Float]: ...
 def move_mean(
-    array: NDArray[np.float64], length: int, min_length: int, parallel: bool
-) -> NDArray[np.float64]: ...
+    array: NDArray[Float],
+    length: int,
+    min_length: int,
+    parallel: bool,
+    axis: int = 0,
+    center: bool = False,
+) -> NDArray[Float]: ...
 def move_max(
-    array: NDArray[np.float64], length: int, min_length: int, parallel: bool
-) -> NDArray[np.float64]: ...
+    array: NDArray[Float],
+    length: int,
+    min_length: int,
+    parallel: bool,
+    axis: int = 0,
+    center: bool = False,
+) -> NDArray[Float]: ...
 def move_min(
-    array: NDArray[np.float64], length: int, min_length: int, parallel: bool
-) -> NDArray[np.float64]: ...
+    array: NDArray[Float],
+    length: int,
+    min_length: int,
+    parallel: bool,
+    axis: int = 0,
+    center: bool = False,
+) -> NDArray[Float]: ...
+def move_argmax(
+    array: NDArray[Float],
+    length: int,
+    min_length: int,
+    parallel: bool,
+    axis: int = 0,
+    center: bool = False,
+) -> NDArray[Float]: ...
+def move_argmin(
+    array: NDArray[Float],
+    length: int,
+    min_length: int,
+    parallel: bool,
+    axis: int = 0,
+    center: bool = False,
+) -> NDArray[Float]: ...
 def move_median(
-    array: NDArray[np.float64], length: int, min_length: int, parallel: bool
-) -> NDArray[np.float64]: ...
+    array: NDArray[Float],
+    length: int,
+    min_length: int,
+    parallel: bool,
+    axis: int = 0,
+    center: bool = False,
+) -> NDArray[Float]: ...
+def move_quantile(
+    array: NDArray[Float],
+    length: int,
+    min_length: int,
+    q: float,
+    interpolation: Interpolation,
+    parallel: bool,
+    axis: int = 0,
+    center: bool = False,
+) -> NDArray[Float]: ...
+def move_quantiles(
+    array: NDArray[Float],
+    length: int,
+    min_length: int,
+    qs: list[float],
+    interpolation: Interpolation,
+    parallel: bool,
+    axis: int = 0,
+    center: bool = False,
+) -> NDArray[Float]: ...
 def move_skewness(
-    array: NDArray[np.float64], length: int, min_length: int, parallel: bool
-) -> NDArray[np.float64]: ...
+    array: NDArray[Float],
+    length: int,
+    min_length: int,
+    parallel: bool,
+    axis: int = 0,
+    center: bool = False,
+) -> NDArray[Float]: ...
 def move_kurtosis(
-    array: NDArray[np.float64], length: int, min_length: int, parallel: bool
-) -> NDArray[np.float64]: ...
+    array: NDArray[Float],
+    length: int,
+    min_length: int,
+    parallel: bool,
+    axis: int = 0,
+    center: bool = False,
+) -> NDArray[Float]: ...
 def move_rank(
-    array: NDArray[np.float64], length: int, min_length: int, parallel: bool
+    array: NDArray[Float],
+    length: int,
+    min_length: int,
+    parallel: bool,
+    axis: int = 0,
+    center: bool = False,
+) -> NDArray[Float]: ...
+def move_cov(
+    a: NDArray[Float],
+    b: NDArray[Float],
+    length: int,
+    min_length: int,
+    parallel: bool,
+    axis: int = 0,
+    center: bool = False,
+) -> NDArray[Float]: ...
+def move_corr(
+    a: NDArray[Float],
+    b: NDArray[Float],
+    length: int,
+    min_length: int,
+    parallel: bool,
+    axis: int = 0,
+    center: bool = False,
+) -> NDArray[Float]: ...
+def move_cov_with(
+    array: NDArray[Float],
+    target: NDArray[Float],
+    length: int,
+    min_length: int,
+    parallel: bool,
+    axis: int = 0,
+    center: bool = False,
+) -> NDArray[Float]: ...
+def move_corr_with(
+    array: NDArray[Float],
+    target: NDArray[Float],
+    length: int,
+    min_length: int,
+    parallel: bool,
+    axis: int = 0,
+    center: bool = False,
+) -> NDArray[Float]: ...
+def move_beta(
+    array: NDArray[Float],
+    market: NDArray[Float],
+    length: int,
+    min_length: int,
+    parallel: bool,
+    axis: int = 0,
+    center: bool = False,
+) -> NDArray[Float]: ...
+def move_cov_matrix(
+    array: NDArray[Float],
+    length: int,
+    min_length: int,
+    parallel: bool,
+    axis: int = 0,
+    center: bool = False,
+) -> NDArray[Float]: ...
+def move_corr_matrix(
+    array: NDArray[Float],
+    length: int,
+    min_length: int,
+    parallel: bool,
+    axis: int = 0,
+    center: bool = False,
+) -> NDArray[Float]: ...
+def move_linreg(
+    y: NDArray[Float],
+    x: NDArray[Float],
+    length: int,
+    min_length: int,
+    parallel: bool,
+    axis: int = 0,
+    center: bool = False,
+) -> NDArray[Float]: ...
+def move_trend(
+    array: NDArray[Float],
+    length: int,
+    min_length: int,
+    parallel: bool,
+    axis: int = 0,
+    center: bool = False,
+) -> NDArray[Float]: ...
+def move_ols(
+    y: NDArray[Float],
+    x: NDArray[Float],
+    length: int,
+    min_length: int,
+    parallel: bool,
+    axis: int = 0,
+    center: bool = False,
+) -> NDArray[Float]: ...
+def ewm_mean(
+    array: NDArray[Float],
+    min_length: int,
+    parallel: bool,
+    com: float | None = None,
+    span: float | None = None,
+    halflife: float | None = None,
+    alpha: float | None = None,
+    adjust: bool = True,
+    ignore_na: bool = False,
+    axis: int = 0,
+) -> NDArray[Float]: ...
+def ewm_var(
+    array: NDArray[Float],
+    min_length: int,
+    parallel: bool,
+    com: float | None = None,
+    span: float | None = None,
+    halflife: float | None = None,
+    alpha: float | None = None,
+    adjust: bool = True,
+    ignore_na: bool = False,
+    axis: int = 0,
+) -> NDArray[Float]: ...
+def ewm_std(
+    array: NDArray[Float],
+    min_length: int,
+    parallel: bool,
+    com: float | None = None,
+    span: float | None = None,
+    halflife: float | None = None,
+    alpha: float | None = None,
+    adjust: bool = True,
+    ignore_na: bool = False,
+    axis: int = 0,
+) -> NDArray[Float]: ...
+def ewm_cov(
+    a: NDArray[Float],
+    b: NDArray[Float],
+    min_length: int,
+    parallel: bool,
+    com: float | None = None,
+    span: float | None = None,
+    halflife: float | None = None,
+    alpha: float | None = None,
+    adjust: bool = True,
+    ignore_na: bool = False,
+    axis: int = 0,
+) -> NDArray[Float]: ...
+def ewm_corr(
+    a: NDArray[Float],
+    b: NDArray[Float],
+    min_length: int,
+    parallel: bool,
+    com: float | None = None,
+    span: float | None = None,
+    halflife: float | None = None,
+    alpha: float | None = None,
+    adjust: bool = True,
+    ignore_na: bool = False,
+    axis: int = 0,
+) -> NDArray[Float]: ...
+def ewm_cov_matrix(
+    array: NDArray[Float],
+    min_length: int,
+    parallel: bool,
+    com: float | None = None,
+    span: float | None = None,
+    halflife: float | None = None,
+    alpha: float | None = None,
+    adjust: bool = True,
+    ignore_na: bool = False,
+    axis: int = 0,
+) -> NDArray[Float]: ...
+def cum_sum(
+    array: NDArray[Float],
+    min_length: int,
+    parallel: bool,
+    axis: int = 0,
+) -> NDArray[Float]: ...
+def cum_prod(
+    array: NDArray[Float],
+    min_length: int,
+    parallel: bool,
+    axis: int = 0,
+) -> NDArray[Float]: ...
+def cum_max(
+    array: NDArray[Float],
+    min_length: int,
+    parallel: bool,
+    axis: int = 0,
+) -> NDArray[Float]: ...
+def cum_min(
+    array: NDArray[Float],
+    min_length: int,
+    parallel: bool,
+    axis: int = 0,
+) -> NDArray[Float]: ...
+def expanding_mean(
+    array: NDArray[Float],
+    min_length: int,
+    parallel: bool,
+    axis: int = 0,
+) -> NDArray[Float]: ...
+def expanding_var(
+    array: NDArray[Float],
+    min_length: int,
+    parallel: bool,
+    axis: int = 0,
+) -> NDArray[Float]: ...
+def expanding_std(
+    array: NDArray[Float],
+    min_length: int,
+    parallel: bool,
+    axis: int = 0,
+) -> NDArray[Float]: ...
+def expanding_skewness(
+    array: NDArray[Float],
+    min_length: int,
+    parallel: bool,
+    axis: int = 0,
+) -> NDArray[Float]: ...
+def expanding_kurtosis(
+    array: NDArray[Float],
+    min_length: int,
+    parallel: bool,
+    axis: int = 0,
+) -> NDArray[Float]: ...
+def expanding_median(
+    array: NDArray[Float],
+    min_length: int,
+    parallel: bool,
+    axis: int = 0,
+) -> NDArray[Float]: ...
+def move_weighted_sum(
+    array: NDArray[Float],
+    weights: list[float] | NDArray[np.float64] | WinType,
+    min_length: int,
+    parallel: bool,
+    axis: int = 0,
+    center: bool = False,
+    length: int | None = None,
+    std: float | None = None,
+) -> NDArray[Float]: ...
+def move_weighted_mean(
+    array: NDArray[Float],
+    weights: list[float] | NDArray[np.float64] | WinType,
+    min_length: int,
+    parallel: bool,
+    axis: int = 0,
+    center: bool = False,
+    length: int | None = None,
+    std: float | None = None,
+) -> NDArray[Float]: ...
+def move_wma(
+    array: NDArray[Float],
+    length: int,
+    min_length: int,
+    parallel: bool,
+    axis: int = 0,
+    center: bool = False,
+) -> NDArray[Float]: ...
+def get_window(
+    win_type: WinType,
+    length: int,
+    std: float | None = None,
 ) -> NDArray[np.float64]: ...
-def agg_sum(array: NDArray[np.float64], parallel: bool) -> NDArray[np.float64]: ...
-def agg_mean(array: NDArray[np.float64], parallel: bool) -> NDArray[np.float64]: ...
-def agg_std(array: NDArray[np.float64], parallel: bool) -> NDArray[np.float64]: ...
-def agg_var(array: NDArray[np.float64], parallel: bool) -> NDArray[np.float64]: ...
-def agg_max(array: NDArray[np.float64], parallel: bool) -> NDArray[np.float64]: ...
-def agg_min(array: NDArray[np.float64], parallel: bool) -> NDArray[np.float64]: ...
-def agg_median(array: NDArray[np.float64]) -> NDArray[np.float64]: ...
-def agg_skewness(array: NDArray[np.float64], parallel: bool) -> NDArray[np.float64]: ...
-def agg_kurtosis(array: NDArray[np.float64], parallel: bool) -> NDArray[np.float64]: ...
-def agg_rank(array: NDArray[np.float64]) -> NDArray[np.float64]: ...
+def agg_sum(
+    array: NDArray[Float], parallel: bool, axis: int = 0
+) -> NDArray[Float]: ...
+def agg_mean(
+    array: NDArray[Float], parallel: bool, axis: int = 0
+) -> NDArray[Float]: ...
+def agg_std(
+    array: NDArray[Float], parallel: bool, axis: int = 0
+) -> NDArray[Float]: ...
+def agg_var(
+    array: NDArray[Float], parallel: bool, axis: int = 0
+) -> NDArray[Float]: ...
+def agg_max(
+    array: NDArray[Float], parallel: bool, axis: int = 0
+) -> NDArray[Float]: ...
+def agg_min(
+    array: NDArray[Float], parallel: bool, axis: int = 0
+) -> NDArray[Float]: ...
+def agg_argmax(
+    array: NDArray[Float], parallel: bool, axis: int = 0
+) -> NDArray[np.int64]: ...
+def agg_argmin(
+    array: NDArray[Float], parallel: bool, axis: int = 0
+) -> NDArray[np.int64]: ...
+def agg_median(
+    array: NDArray[Float], parallel: bool, axis: int = 0
+) -> NDArray[Float]: ...
+def agg_skewness(
+    array: NDArray[Float], parallel: bool, axis: int = 0
+) -> NDArray[Float]: ...
+def agg_kurtosis(
+    array: NDArray[Float], parallel: bool, axis: int = 0
+) -> NDArray[Float]: ...
+def agg_rank(
+    array: NDArray[Float], parallel: bool, axis: int = 0
+) -> NDArray[Float]: ...
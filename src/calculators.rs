@@ -1,83 +1,580 @@
 use crate::stats;
+use std::cmp::Ordering;
 use std::collections::VecDeque;
 use numpy::ndarray::{ ArrayBase, ViewRepr, Dim };
+use numpy::Element;
+
+pub trait Float: Element + Copy + PartialOrd + Send + Sync + 'static {
+    const NAN: Self;
+
+    fn is_nan(self) -> bool;
+    fn to_f64(self) -> f64;
+    fn from_f64(value: f64) -> Self;
+    fn total_cmp(&self, other: &Self) -> Ordering;
+}
+
+impl Float for f64 {
+    const NAN: Self = f64::NAN;
+
+    #[inline(always)]
+    fn is_nan(self) -> bool {
+        f64::is_nan(self)
+    }
+    #[inline(always)]
+    fn to_f64(self) -> f64 {
+        self
+    }
+    #[inline(always)]
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+    #[inline(always)]
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        f64::total_cmp(self, other)
+    }
+}
+
+impl Float for f32 {
+    const NAN: Self = f32::NAN;
+
+    #[inline(always)]
+    fn is_nan(self) -> bool {
+        f32::is_nan(self)
+    }
+    #[inline(always)]
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+    #[inline(always)]
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+    #[inline(always)]
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        f32::total_cmp(self, other)
+    }
+}
+
+const REFRESH_PERIOD: f64 = 4.0;
+const CANCELLATION_RATIO: f64 = 1e-4;
+const RIDGE_FACTOR: f64 = 1e-8;
+
+#[inline(always)]
+fn moments_are_stale(obs: f64, m2: f64, peak: f64, removals: usize) -> bool {
+    (removals as f64) > REFRESH_PERIOD * obs || m2 < CANCELLATION_RATIO * peak
+}
+
 pub struct Squared {
-    sum_simple: f64,
-    sum_squared: f64,
+    obs: f64,
+    shift: f64,
+    mean: f64,
+    m2: f64,
+    peak: f64,
+    removals: usize,
 }
 
 impl Squared {
     #[inline(always)]
     pub fn new() -> Self {
         Self {
-            sum_simple: 0.0,
-            sum_squared: 0.0,
+            obs: 0.0,
+            shift: 0.0,
+            mean: 0.0,
+            m2: 0.0,
+            peak: 0.0,
+            removals: 0,
+        }
+    }
+    #[inline(always)]
+    fn add(&mut self, value: f64) {
+        if self.obs == 0.0 {
+            self.shift = value;
+        }
+        let value: f64 = value - self.shift;
+        self.obs += 1.0;
+        let delta: f64 = value - self.mean;
+        self.mean += delta / self.obs;
+        self.m2 += delta * (value - self.mean);
+        self.peak = self.peak.max(self.m2);
+    }
+    #[inline(always)]
+    fn remove(&mut self, value: f64) {
+        if self.obs <= 1.0 {
+            *self = Self::new();
+            return;
         }
+        let value: f64 = value - self.shift;
+        self.removals += 1;
+        self.obs -= 1.0;
+        let delta: f64 = value - self.mean;
+        self.mean -= delta / self.obs;
+        self.m2 -= delta * (value - self.mean);
+    }
+    #[inline(always)]
+    fn is_stale(&self) -> bool {
+        moments_are_stale(self.obs, self.m2, self.peak, self.removals)
     }
 }
 
 pub struct Cubic {
-    sum_simple: f64,
-    sum_squared: f64,
-    sum_cubed: f64,
-    compensation_cubed: f64,
+    obs: f64,
+    shift: f64,
+    mean: f64,
+    m2: f64,
+    m3: f64,
+    peak: f64,
+    removals: usize,
 }
 
 impl Cubic {
     #[inline(always)]
     pub fn new() -> Self {
         Self {
-            sum_simple: 0.0,
-            sum_squared: 0.0,
-            sum_cubed: 0.0,
-            compensation_cubed: 0.0,
+            obs: 0.0,
+            shift: 0.0,
+            mean: 0.0,
+            m2: 0.0,
+            m3: 0.0,
+            peak: 0.0,
+            removals: 0,
+        }
+    }
+    #[inline(always)]
+    fn add(&mut self, value: f64) {
+        if self.obs == 0.0 {
+            self.shift = value;
+        }
+        let value: f64 = value - self.shift;
+        let previous_obs: f64 = self.obs;
+        self.obs += 1.0;
+        let delta: f64 = value - self.mean;
+        let delta_n: f64 = delta / self.obs;
+        let term: f64 = delta * delta_n * previous_obs;
+
+        self.mean += delta_n;
+        self.m3 += term * delta_n * (self.obs - 2.0) - 3.0 * delta_n * self.m2;
+        self.m2 += term;
+        self.peak = self.peak.max(self.m2);
+    }
+    #[inline(always)]
+    fn remove(&mut self, value: f64) {
+        if self.obs <= 1.0 {
+            *self = Self::new();
+            return;
         }
+        let value: f64 = value - self.shift;
+        self.removals += 1;
+        let previous_obs: f64 = self.obs - 1.0;
+        let previous_mean: f64 = self.mean - (value - self.mean) / previous_obs;
+        let delta: f64 = value - previous_mean;
+        let delta_n: f64 = delta / self.obs;
+        let term: f64 = delta * delta_n * previous_obs;
+
+        self.m2 -= term;
+        self.m3 -= term * delta_n * (self.obs - 2.0) - 3.0 * delta_n * self.m2;
+        self.mean = previous_mean;
+        self.obs = previous_obs;
+    }
+    #[inline(always)]
+    fn is_stale(&self) -> bool {
+        moments_are_stale(self.obs, self.m2, self.peak, self.removals)
     }
 }
 
 pub struct Quadratric {
-    sum_simple: f64,
-    sum_squared: f64,
-    sum_cubed: f64,
-    compensation_cubed: f64,
-    sum_quad: f64,
-    compensation_quad: f64,
+    obs: f64,
+    shift: f64,
+    mean: f64,
+    m2: f64,
+    m3: f64,
+    m4: f64,
+    peak: f64,
+    removals: usize,
 }
 
 impl Quadratric {
     #[inline(always)]
     pub fn new() -> Self {
         Self {
-            sum_simple: 0.0,
-            sum_squared: 0.0,
-            sum_cubed: 0.0,
-            compensation_cubed: 0.0,
-            sum_quad: 0.0,
-            compensation_quad: 0.0,
+            obs: 0.0,
+            shift: 0.0,
+            mean: 0.0,
+            m2: 0.0,
+            m3: 0.0,
+            m4: 0.0,
+            peak: 0.0,
+            removals: 0,
+        }
+    }
+    #[inline(always)]
+    fn add(&mut self, value: f64) {
+        if self.obs == 0.0 {
+            self.shift = value;
+        }
+        let value: f64 = value - self.shift;
+        let previous_obs: f64 = self.obs;
+        self.obs += 1.0;
+        let delta: f64 = value - self.mean;
+        let delta_n: f64 = delta / self.obs;
+        let delta_n2: f64 = delta_n * delta_n;
+        let term: f64 = delta * delta_n * previous_obs;
+
+        self.mean += delta_n;
+        self.m4 +=
+            term * delta_n2 * (self.obs * self.obs - 3.0 * self.obs + 3.0) +
+            6.0 * delta_n2 * self.m2 -
+            4.0 * delta_n * self.m3;
+        self.m3 += term * delta_n * (self.obs - 2.0) - 3.0 * delta_n * self.m2;
+        self.m2 += term;
+        self.peak = self.peak.max(self.m2);
+    }
+    #[inline(always)]
+    fn remove(&mut self, value: f64) {
+        if self.obs <= 1.0 {
+            *self = Self::new();
+            return;
+        }
+        let value: f64 = value - self.shift;
+        self.removals += 1;
+        let previous_obs: f64 = self.obs - 1.0;
+        let previous_mean: f64 = self.mean - (value - self.mean) / previous_obs;
+        let delta: f64 = value - previous_mean;
+        let delta_n: f64 = delta / self.obs;
+        let delta_n2: f64 = delta_n * delta_n;
+        let term: f64 = delta * delta_n * previous_obs;
+
+        self.m2 -= term;
+        self.m3 -= term * delta_n * (self.obs - 2.0) - 3.0 * delta_n * self.m2;
+        self.m4 -=
+            term * delta_n2 * (self.obs * self.obs - 3.0 * self.obs + 3.0) +
+            6.0 * delta_n2 * self.m2 -
+            4.0 * delta_n * self.m3;
+        self.mean = previous_mean;
+        self.obs = previous_obs;
+    }
+    #[inline(always)]
+    fn is_stale(&self) -> bool {
+        moments_are_stale(self.obs, self.m2, self.peak, self.removals)
+    }
+}
+
+pub struct CoMoments {
+    obs: f64,
+    shift_x: f64,
+    shift_y: f64,
+    mean_x: f64,
+    mean_y: f64,
+    m2_x: f64,
+    m2_y: f64,
+    cxy: f64,
+    peak_x: f64,
+    peak_y: f64,
+    removals: usize,
+}
+
+impl CoMoments {
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self {
+            obs: 0.0,
+            shift_x: 0.0,
+            shift_y: 0.0,
+            mean_x: 0.0,
+            mean_y: 0.0,
+            m2_x: 0.0,
+            m2_y: 0.0,
+            cxy: 0.0,
+            peak_x: 0.0,
+            peak_y: 0.0,
+            removals: 0,
+        }
+    }
+    #[inline(always)]
+    pub fn add(&mut self, x: f64, y: f64) {
+        if self.obs == 0.0 {
+            self.shift_x = x;
+            self.shift_y = y;
+        }
+        let x: f64 = x - self.shift_x;
+        let y: f64 = y - self.shift_y;
+        self.obs += 1.0;
+        let delta_x: f64 = x - self.mean_x;
+        let delta_y: f64 = y - self.mean_y;
+        self.mean_x += delta_x / self.obs;
+        self.mean_y += delta_y / self.obs;
+        self.m2_x += delta_x * (x - self.mean_x);
+        self.m2_y += delta_y * (y - self.mean_y);
+        self.cxy += delta_x * (y - self.mean_y);
+        self.peak_x = self.peak_x.max(self.m2_x);
+        self.peak_y = self.peak_y.max(self.m2_y);
+    }
+    #[inline(always)]
+    pub fn remove(&mut self, x: f64, y: f64) {
+        if self.obs <= 1.0 {
+            *self = Self::new();
+            return;
+        }
+        let x: f64 = x - self.shift_x;
+        let y: f64 = y - self.shift_y;
+        self.removals += 1;
+        self.obs -= 1.0;
+        let delta_x: f64 = x - self.mean_x;
+        let delta_y: f64 = y - self.mean_y;
+        self.mean_x -= delta_x / self.obs;
+        self.mean_y -= delta_y / self.obs;
+        self.m2_x -= delta_x * (x - self.mean_x);
+        self.m2_y -= delta_y * (y - self.mean_y);
+        self.cxy -= delta_x * (y - self.mean_y);
+    }
+    #[inline(always)]
+    pub fn regression(&self) -> (f64, f64, f64) {
+        let slope: f64 = stats::beta(self.m2_x, self.cxy);
+        let intercept: f64 = self.mean_y + self.shift_y - slope * (self.mean_x + self.shift_x);
+        let rsquared: f64 = stats::corr(self.m2_x, self.m2_y, self.cxy).powi(2);
+        (slope, intercept, rsquared)
+    }
+    #[inline(always)]
+    pub fn residual(&self, x: f64, y: f64) -> f64 {
+        let slope: f64 = stats::beta(self.m2_x, self.cxy);
+        y - self.shift_y - self.mean_y - slope * (x - self.shift_x - self.mean_x)
+    }
+    #[inline(always)]
+    pub fn is_stale(&self) -> bool {
+        moments_are_stale(self.obs, self.m2_x, self.peak_x, self.removals) ||
+            moments_are_stale(self.obs, self.m2_y, self.peak_y, self.removals)
+    }
+}
+
+pub struct Ols {
+    obs: f64,
+    xtx: Vec<f64>,
+    xty: Vec<f64>,
+    factor: Vec<f64>,
+    pub coefficients: Vec<f64>,
+    removals: usize,
+}
+
+impl Ols {
+    pub fn new(size: usize) -> Self {
+        Self {
+            obs: 0.0,
+            xtx: vec![0.0; size * size],
+            xty: vec![0.0; size],
+            factor: vec![0.0; size * size],
+            coefficients: vec![0.0; size],
+            removals: 0,
+        }
+    }
+    pub fn reset(&mut self) {
+        self.obs = 0.0;
+        self.xtx.fill(0.0);
+        self.xty.fill(0.0);
+        self.removals = 0;
+    }
+    #[inline(always)]
+    pub fn add(&mut self, x: &[f64], y: f64) {
+        let size: usize = x.len();
+        self.obs += 1.0;
+        for i in 0..size {
+            self.xty[i] += x[i] * y;
+            for j in 0..=i {
+                self.xtx[i * size + j] += x[i] * x[j];
+            }
+        }
+    }
+    #[inline(always)]
+    pub fn remove(&mut self, x: &[f64], y: f64) {
+        if self.obs <= 1.0 {
+            self.reset();
+            return;
+        }
+        let size: usize = x.len();
+        self.removals += 1;
+        self.obs -= 1.0;
+        for i in 0..size {
+            self.xty[i] -= x[i] * y;
+            for j in 0..=i {
+                self.xtx[i * size + j] -= x[i] * x[j];
+            }
+        }
+    }
+    #[inline(always)]
+    pub fn is_stale(&self) -> bool {
+        (self.removals as f64) > REFRESH_PERIOD * self.obs
+    }
+    pub fn solve(&mut self) -> bool {
+        let solved: bool = stats::cholesky_solve(
+            &self.xtx,
+            &self.xty,
+            0.0,
+            &mut self.factor,
+            &mut self.coefficients
+        );
+        if solved {
+            return true;
+        }
+        let size: usize = self.xty.len();
+        let trace: f64 = (0..size).map(|i| self.xtx[i * size + i]).sum();
+        let ridge: f64 = RIDGE_FACTOR * (trace / (size as f64)).max(f64::MIN_POSITIVE);
+        stats::cholesky_solve(&self.xtx, &self.xty, ridge, &mut self.factor, &mut self.coefficients)
+    }
+}
+
+pub struct Ewm {
+    old_weight_factor: f64,
+    new_weight: f64,
+    adjust: bool,
+    ignore_na: bool,
+    mean_x: f64,
+    mean_y: f64,
+    cov: f64,
+    var_x: f64,
+    var_y: f64,
+    sum_weights: f64,
+    sum_squared_weights: f64,
+    old_weight: f64,
+}
+
+impl Ewm {
+    pub fn new(alpha: f64, adjust: bool, ignore_na: bool) -> Self {
+        Self {
+            old_weight_factor: 1.0 - alpha,
+            new_weight: if adjust { 1.0 } else { alpha },
+            adjust,
+            ignore_na,
+            mean_x: f64::NAN,
+            mean_y: f64::NAN,
+            cov: 0.0,
+            var_x: 0.0,
+            var_y: 0.0,
+            sum_weights: 1.0,
+            sum_squared_weights: 1.0,
+            old_weight: 1.0,
+        }
+    }
+    #[inline(always)]
+    pub fn update(&mut self, x: f64, y: f64) {
+        let is_observation: bool = !x.is_nan() && !y.is_nan();
+        if self.mean_x.is_nan() {
+            if is_observation {
+                self.mean_x = x;
+                self.mean_y = y;
+            }
+            return;
+        }
+        if !is_observation && self.ignore_na {
+            return;
+        }
+
+        self.sum_weights *= self.old_weight_factor;
+        self.sum_squared_weights *= self.old_weight_factor * self.old_weight_factor;
+        self.old_weight *= self.old_weight_factor;
+        if !is_observation {
+            return;
+        }
+
+        let total_weight: f64 = self.old_weight + self.new_weight;
+        let (old_mean_x, old_mean_y) = (self.mean_x, self.mean_y);
+        self.mean_x = (self.old_weight * old_mean_x + self.new_weight * x) / total_weight;
+        self.mean_y = (self.old_weight * old_mean_y + self.new_weight * y) / total_weight;
+        let (shift_x, shift_y) = (old_mean_x - self.mean_x, old_mean_y - self.mean_y);
+        let (delta_x, delta_y) = (x - self.mean_x, y - self.mean_y);
+        self.cov =
+            (self.old_weight * (self.cov + shift_x * shift_y) +
+                self.new_weight * delta_x * delta_y) /
+            total_weight;
+        self.var_x =
+            (self.old_weight * (self.var_x + shift_x * shift_x) +
+                self.new_weight * delta_x * delta_x) /
+            total_weight;
+        self.var_y =
+            (self.old_weight * (self.var_y + shift_y * shift_y) +
+                self.new_weight * delta_y * delta_y) /
+            total_weight;
+        self.sum_weights += self.new_weight;
+        self.sum_squared_weights += self.new_weight * self.new_weight;
+        self.old_weight = total_weight;
+        if !self.adjust {
+            self.sum_weights /= self.old_weight;
+            self.sum_squared_weights /= self.old_weight * self.old_weight;
+            self.old_weight = 1.0;
+        }
+    }
+}
+
+pub struct Linear {
+    length: f64,
+    sum: f64,
+    weighted_sum: f64,
+    count: f64,
+    total_weight: f64,
+    absolute_sum: f64,
+    peak: f64,
+    steps: usize,
+}
+
+impl Linear {
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self {
+            length: 0.0,
+            sum: 0.0,
+            weighted_sum: 0.0,
+            count: 0.0,
+            total_weight: 0.0,
+            absolute_sum: 0.0,
+            peak: 0.0,
+            steps: 0,
         }
     }
+    #[inline(always)]
+    fn advance(&mut self) {
+        self.steps += 1;
+        self.weighted_sum -= self.sum;
+        self.total_weight -= self.count;
+    }
+    #[inline(always)]
+    fn add(&mut self, value: f64) {
+        self.sum += value;
+        self.weighted_sum += self.length * value;
+        self.count += 1.0;
+        self.total_weight += self.length;
+        self.absolute_sum += value.abs();
+        self.peak = self.peak.max(self.absolute_sum);
+    }
+    #[inline(always)]
+    fn remove(&mut self, value: f64) {
+        self.sum -= value;
+        self.count -= 1.0;
+        self.absolute_sum -= value.abs();
+    }
+    #[inline(always)]
+    fn is_stale(&self) -> bool {
+        (self.steps as f64) > REFRESH_PERIOD * self.length ||
+            self.absolute_sum < CANCELLATION_RATIO * self.peak
+    }
 }
 
-pub struct WindowState {
+pub struct WindowState<T: Float> {
     pub observations: usize,
-    pub current: f64,
-    pub precedent: f64,
+    pub current: T,
+    pub precedent: T,
     pub precedent_idx: usize,
 }
-impl WindowState {
+impl<T: Float> WindowState<T> {
     #[inline(always)]
     pub fn new() -> Self {
         Self {
             observations: 0,
-            current: f64::NAN,
-            precedent: f64::NAN,
+            current: T::NAN,
+            precedent: T::NAN,
             precedent_idx: 0,
         }
     }
     #[inline(always)]
     pub fn refresh(
         &mut self,
-        input_col: &ArrayBase<ViewRepr<&f64>, Dim<[usize; 1]>>,
+        input_col: &ArrayBase<ViewRepr<&T>, Dim<[usize; 1]>>,
         row: usize,
         length: usize
     ) {
@@ -87,6 +584,7 @@ impl WindowState {
     }
     #[inline(always)]
     pub fn compute_row<Calculator: StatCalculator>(&mut self, state: &mut Calculator::Accumulator) {
+        Calculator::advance(state);
         if !self.current.is_nan() {
             self.observations += 1;
             Calculator::add_value(state, self.current);
@@ -100,8 +598,9 @@ impl WindowState {
     #[inline(always)]
     pub fn compute_deque_row<Calculator: DequeStatCalculator>(
         &mut self,
-        deque: &mut VecDeque<(f64, usize)>,
-        row: usize
+        deque: &mut VecDeque<(T, usize)>,
+        row: usize,
+        latest: bool
     ) {
         if !self.precedent.is_nan() {
             self.observations -= 1;
@@ -114,7 +613,11 @@ impl WindowState {
 
         if !self.current.is_nan() {
             self.observations += 1;
-            Calculator::add_value(deque, self.current, row);
+            if latest {
+                Calculator::add_latest_value(deque, self.current, row);
+            } else {
+                Calculator::add_value(deque, self.current, row);
+            }
         }
     }
 }
@@ -123,14 +626,48 @@ pub trait StatCalculator {
     type Accumulator;
 
     fn new() -> Self::Accumulator;
-    fn add_value(state: &mut Self::Accumulator, value: f64);
-    fn remove_value(state: &mut Self::Accumulator, value: f64);
-    fn get(state: &Self::Accumulator, count: usize) -> f64;
+    fn add_value<T: Float>(state: &mut Self::Accumulator, value: T);
+    fn remove_value<T: Float>(state: &mut Self::Accumulator, value: T);
+    fn get<T: Float>(state: &Self::Accumulator, count: usize) -> T;
+    fn is_stale(_state: &Self::Accumulator) -> bool {
+        false
+    }
+    fn set_length(_state: &mut Self::Accumulator, _length: usize) {}
+    fn advance(_state: &mut Self::Accumulator) {}
 }
 
 pub trait DequeStatCalculator {
-    fn new() -> VecDeque<(f64, usize)>;
-    fn add_value(deque: &mut VecDeque<(f64, usize)>, value: f64, idx: usize);
+    #[allow(clippy::new_ret_no_self)]
+    fn new<T: Float>() -> VecDeque<(T, usize)>;
+    fn is_better<T: Float>(candidate: T, current: T) -> bool;
+    fn add_value<T: Float>(deque: &mut VecDeque<(T, usize)>, value: T, idx: usize) {
+        while let Some(&(val, _)) = deque.back() {
+            if Self::is_better(value, val) {
+                deque.pop_back();
+            } else {
+                break;
+            }
+        }
+        deque.push_back((value, idx));
+    }
+    fn add_latest_value<T: Float>(deque: &mut VecDeque<(T, usize)>, value: T, idx: usize) {
+        while let Some(&(val, _)) = deque.back() {
+            if !Self::is_better(val, value) {
+                deque.pop_back();
+            } else {
+                break;
+            }
+        }
+        deque.push_back((value, idx));
+    }
+}
+
+pub trait EwmStatCalculator {
+    fn get(state: &Ewm) -> f64;
+}
+
+pub trait PairStatCalculator {
+    fn get<T: Float>(state: &CoMoments) -> T;
 }
 pub struct Sum;
 impl StatCalculator for Sum {
@@ -139,14 +676,44 @@ impl StatCalculator for Sum {
     fn new() -> Self::Accumulator {
         0.0
     }
-    fn add_value(state: &mut Self::Accumulator, value: f64) {
+    fn add_value<T: Float>(state: &mut Self::Accumulator, value: T) {
+        let value: f64 = value.to_f64();
         *state += value;
     }
-    fn remove_value(state: &mut Self::Accumulator, value: f64) {
+    fn remove_value<T: Float>(state: &mut Self::Accumulator, value: T) {
+        let value: f64 = value.to_f64();
         *state -= value;
     }
-    fn get(state: &Self::Accumulator, _count: usize) -> f64 {
-        *state
+    fn get<T: Float>(state: &Self::Accumulator, _count: usize) -> T {
+        T::from_f64(*state)
+    }
+}
+
+pub struct Product;
+impl StatCalculator for Product {
+    type Accumulator = (f64, usize);
+
+    fn new() -> Self::Accumulator {
+        (1.0, 0)
+    }
+    fn add_value<T: Float>(state: &mut Self::Accumulator, value: T) {
+        let value: f64 = value.to_f64();
+        if value == 0.0 {
+            state.1 += 1;
+        } else {
+            state.0 *= value;
+        }
+    }
+    fn remove_value<T: Float>(state: &mut Self::Accumulator, value: T) {
+        let value: f64 = value.to_f64();
+        if value == 0.0 {
+            state.1 -= 1;
+        } else {
+            state.0 /= value;
+        }
+    }
+    fn get<T: Float>(state: &Self::Accumulator, _count: usize) -> T {
+        if state.1 > 0 { T::from_f64(0.0) } else { T::from_f64(state.0) }
     }
 }
 
@@ -157,16 +724,45 @@ impl StatCalculator for Mean {
     fn new() -> Self::Accumulator {
         0.0
     }
-    fn add_value(state: &mut Self::Accumulator, value: f64) {
+    fn add_value<T: Float>(state: &mut Self::Accumulator, value: T) {
+        let value: f64 = value.to_f64();
         *state += value;
     }
-    fn remove_value(state: &mut Self::Accumulator, value: f64) {
+    fn remove_value<T: Float>(state: &mut Self::Accumulator, value: T) {
+        let value: f64 = value.to_f64();
         *state -= value;
     }
-    fn get(state: &Self::Accumulator, count: usize) -> f64 {
-        *state / (count as f64)
+    fn get<T: Float>(state: &Self::Accumulator, count: usize) -> T {
+        T::from_f64(*state / (count as f64))
     }
 }
+pub struct Wma;
+impl StatCalculator for Wma {
+    type Accumulator = Linear;
+
+    fn new() -> Self::Accumulator {
+        Linear::new()
+    }
+    fn add_value<T: Float>(state: &mut Self::Accumulator, value: T) {
+        state.add(value.to_f64());
+    }
+    fn remove_value<T: Float>(state: &mut Self::Accumulator, value: T) {
+        state.remove(value.to_f64());
+    }
+    fn get<T: Float>(state: &Self::Accumulator, _count: usize) -> T {
+        T::from_f64(state.weighted_sum / state.total_weight)
+    }
+    fn is_stale(state: &Self::Accumulator) -> bool {
+        state.is_stale()
+    }
+    fn set_length(state: &mut Self::Accumulator, length: usize) {
+        state.length = length as f64;
+    }
+    fn advance(state: &mut Self::Accumulator) {
+        state.advance();
+    }
+}
+
 pub struct Var;
 impl StatCalculator for Var {
     type Accumulator = Squared;
@@ -174,16 +770,17 @@ impl StatCalculator for Var {
     fn new() -> Self::Accumulator {
         Squared::new()
     }
-    fn add_value(state: &mut Self::Accumulator, value: f64) {
-        state.sum_simple += value;
-        state.sum_squared += value.powi(2);
+    fn add_value<T: Float>(state: &mut Self::Accumulator, value: T) {
+        state.add(value.to_f64());
     }
-    fn remove_value(state: &mut Self::Accumulator, value: f64) {
-        state.sum_simple -= value;
-        state.sum_squared -= value.powi(2);
+    fn remove_value<T: Float>(state: &mut Self::Accumulator, value: T) {
+        state.remove(value.to_f64());
     }
-    fn get(state: &Self::Accumulator, count: usize) -> f64 {
-        stats::var(state.sum_simple, state.sum_squared, count as f64)
+    fn get<T: Float>(state: &Self::Accumulator, count: usize) -> T {
+        T::from_f64(stats::var(state.m2, count as f64))
+    }
+    fn is_stale(state: &Self::Accumulator) -> bool {
+        state.is_stale()
     }
 }
 
@@ -194,16 +791,17 @@ impl StatCalculator for Stdev {
     fn new() -> Self::Accumulator {
         Squared::new()
     }
-    fn add_value(state: &mut Self::Accumulator, value: f64) {
-        state.sum_simple += value;
-        state.sum_squared += value.powi(2);
+    fn add_value<T: Float>(state: &mut Self::Accumulator, value: T) {
+        state.add(value.to_f64());
+    }
+    fn remove_value<T: Float>(state: &mut Self::Accumulator, value: T) {
+        state.remove(value.to_f64());
     }
-    fn remove_value(state: &mut Self::Accumulator, value: f64) {
-        state.sum_simple -= value;
-        state.sum_squared -= value.powi(2);
+    fn get<T: Float>(state: &Self::Accumulator, count: usize) -> T {
+        T::from_f64(stats::stdev(state.m2, count as f64))
     }
-    fn get(state: &Self::Accumulator, count: usize) -> f64 {
-        stats::stdev(state.sum_simple, state.sum_squared, count as f64)
+    fn is_stale(state: &Self::Accumulator) -> bool {
+        state.is_stale()
     }
 }
 
@@ -214,26 +812,17 @@ impl StatCalculator for Skewness {
     fn new() -> Self::Accumulator {
         Cubic::new()
     }
-    fn add_value(state: &mut Self::Accumulator, value: f64) {
-        state.sum_simple += value;
-        state.sum_squared += value.powi(2);
-
-        let temp: f64 = value.powi(3) - state.compensation_cubed;
-        let total: f64 = state.sum_cubed + temp;
-        state.compensation_cubed = total - state.sum_cubed - temp;
-        state.sum_cubed = total;
+    fn add_value<T: Float>(state: &mut Self::Accumulator, value: T) {
+        state.add(value.to_f64());
     }
-    fn remove_value(state: &mut Self::Accumulator, value: f64) {
-        state.sum_simple -= value;
-        state.sum_squared -= value.powi(2);
-
-        let temp: f64 = -value.powi(3) - state.compensation_cubed;
-        let total: f64 = state.sum_cubed + temp;
-        state.compensation_cubed = total - state.sum_cubed - temp;
-        state.sum_cubed = total;
+    fn remove_value<T: Float>(state: &mut Self::Accumulator, value: T) {
+        state.remove(value.to_f64());
+    }
+    fn get<T: Float>(state: &Self::Accumulator, count: usize) -> T {
+        T::from_f64(stats::skew(state.m2, state.m3, count as f64))
     }
-    fn get(state: &Self::Accumulator, count: usize) -> f64 {
-        stats::skew(state.sum_simple, state.sum_squared, state.sum_cubed, count as f64)
+    fn is_stale(state: &Self::Accumulator) -> bool {
+        state.is_stale()
     }
 }
 pub struct Kurtosis;
@@ -243,120 +832,279 @@ impl StatCalculator for Kurtosis {
     fn new() -> Self::Accumulator {
         Quadratric::new()
     }
-    fn add_value(state: &mut Self::Accumulator, value: f64) {
-        state.sum_simple += value;
-        state.sum_squared += value.powi(2);
-
-        let temp: f64 = value.powi(3) - state.compensation_cubed;
-        let total: f64 = state.sum_cubed + temp;
-        state.compensation_cubed = total - state.sum_cubed - temp;
-        state.sum_cubed = total;
-
-        let temp: f64 = value.powi(4) - state.compensation_quad;
-        let total: f64 = state.sum_quad + temp;
-        state.compensation_quad = total - state.sum_quad - temp;
-        state.sum_quad = total;
-    }
-    fn remove_value(state: &mut Self::Accumulator, value: f64) {
-        state.sum_simple -= value;
-        state.sum_squared -= value.powi(2);
-
-        let temp: f64 = -value.powi(3) - state.compensation_cubed;
-        let total: f64 = state.sum_cubed + temp;
-        state.compensation_cubed = total - state.sum_cubed - temp;
-        state.sum_cubed = total;
-
-        let temp: f64 = -value.powi(4) - state.compensation_quad;
-        let total: f64 = state.sum_quad + temp;
-        state.compensation_quad = total - state.sum_quad - temp;
-        state.sum_quad = total;
-    }
-    fn get(state: &Self::Accumulator, count: usize) -> f64 {
-        stats::kurtosis(
-            state.sum_simple,
-            state.sum_squared,
-            state.sum_cubed,
-            state.sum_quad,
-            count as f64
-        )
+    fn add_value<T: Float>(state: &mut Self::Accumulator, value: T) {
+        state.add(value.to_f64());
+    }
+    fn remove_value<T: Float>(state: &mut Self::Accumulator, value: T) {
+        state.remove(value.to_f64());
+    }
+    fn get<T: Float>(state: &Self::Accumulator, count: usize) -> T {
+        T::from_f64(stats::kurtosis(state.m2, state.m4, count as f64))
+    }
+    fn is_stale(state: &Self::Accumulator) -> bool {
+        state.is_stale()
+    }
+}
+
+pub struct Cov;
+impl PairStatCalculator for Cov {
+    fn get<T: Float>(state: &CoMoments) -> T {
+        T::from_f64(stats::cov(state.cxy, state.obs))
+    }
+}
+
+pub struct Corr;
+impl PairStatCalculator for Corr {
+    fn get<T: Float>(state: &CoMoments) -> T {
+        T::from_f64(stats::corr(state.m2_x, state.m2_y, state.cxy))
+    }
+}
+
+pub struct Beta;
+impl PairStatCalculator for Beta {
+    fn get<T: Float>(state: &CoMoments) -> T {
+        T::from_f64(stats::beta(state.m2_y, state.cxy))
+    }
+}
+
+pub struct EwmMean;
+impl EwmStatCalculator for EwmMean {
+    fn get(state: &Ewm) -> f64 {
+        state.mean_x
+    }
+}
+
+pub struct EwmVar;
+impl EwmStatCalculator for EwmVar {
+    fn get(state: &Ewm) -> f64 {
+        stats::ewm_cov(state.cov, state.sum_weights, state.sum_squared_weights)
+    }
+}
+
+pub struct EwmStd;
+impl EwmStatCalculator for EwmStd {
+    fn get(state: &Ewm) -> f64 {
+        EwmVar::get(state).sqrt()
+    }
+}
+
+pub struct EwmCov;
+impl EwmStatCalculator for EwmCov {
+    fn get(state: &Ewm) -> f64 {
+        stats::ewm_cov(state.cov, state.sum_weights, state.sum_squared_weights)
+    }
+}
+
+pub struct EwmCorr;
+impl EwmStatCalculator for EwmCorr {
+    fn get(state: &Ewm) -> f64 {
+        stats::corr(state.var_x, state.var_y, state.cov)
     }
 }
 
 pub struct Min;
 impl DequeStatCalculator for Min {
-    fn new() -> VecDeque<(f64, usize)> {
+    fn new<T: Float>() -> VecDeque<(T, usize)> {
         VecDeque::new()
     }
 
-    fn add_value(deque: &mut VecDeque<(f64, usize)>, value: f64, idx: usize) {
-        while let Some(&(val, _)) = deque.back() {
-            if val > value {
-                deque.pop_back();
-            } else {
-                break;
-            }
-        }
-        deque.push_back((value, idx));
+    fn is_better<T: Float>(candidate: T, current: T) -> bool {
+        candidate < current
     }
 }
 
 pub struct Max;
 impl DequeStatCalculator for Max {
-    fn new() -> VecDeque<(f64, usize)> {
+    fn new<T: Float>() -> VecDeque<(T, usize)> {
         VecDeque::new()
     }
 
-    fn add_value(deque: &mut VecDeque<(f64, usize)>, value: f64, idx: usize) {
-        while let Some(&(val, _)) = deque.back() {
-            if val < value {
-                deque.pop_back();
+    fn is_better<T: Float>(candidate: T, current: T) -> bool {
+        candidate > current
+    }
+}
+
+pub struct Rank<T: Float> {
+    values: Vec<T>,
+    tree: Vec<usize>,
+    pub valid_count: usize,
+}
+impl<T: Float> Rank<T> {
+    pub fn new(input_col: &ArrayBase<ViewRepr<&T>, Dim<[usize; 1]>>) -> Self {
+        let mut values: Vec<T> = input_col
+            .iter()
+            .copied()
+            .filter(|value| !value.is_nan())
+            .collect();
+        values.sort_unstable_by(T::total_cmp);
+        values.dedup();
+
+        Self {
+            tree: vec![0; values.len() + 1],
+            values,
+            valid_count: 0,
+        }
+    }
+    #[inline(always)]
+    fn position(&self, value: T) -> usize {
+        self.values.partition_point(|&other| other < value)
+    }
+    #[inline(always)]
+    fn update(&mut self, value: T, add: bool) {
+        let mut node: usize = self.position(value) + 1;
+        while node < self.tree.len() {
+            if add {
+                self.tree[node] += 1;
             } else {
-                break;
+                self.tree[node] -= 1;
             }
+            node += node & node.wrapping_neg();
         }
-        deque.push_back((value, idx));
+    }
+    #[inline(always)]
+    fn count_below(&self, position: usize) -> usize {
+        let mut node: usize = position;
+        let mut count: usize = 0;
+        while node > 0 {
+            count += self.tree[node];
+            node -= node & node.wrapping_neg();
+        }
+        count
+    }
+
+    pub fn add(&mut self, value: T) {
+        self.valid_count += 1;
+        self.update(value, true);
+    }
+
+    pub fn remove(&mut self, value: T) {
+        self.valid_count -= 1;
+        self.update(value, false);
+    }
+
+    pub fn get(&self, current: T) -> f64 {
+        let position: usize = self.position(current);
+        let smaller_count: usize = self.count_below(position);
+        let equal_count: usize = self.count_below(position + 1) - smaller_count;
+        stats::rank(2 * smaller_count, equal_count, self.valid_count as f64)
+    }
+
+    #[inline(always)]
+    fn nth(&self, k: usize) -> T {
+        let mut node: usize = 0;
+        let mut remaining: usize = k;
+        let mut step: usize = (self.tree.len() - 1).next_power_of_two();
+
+        while step > 0 {
+            let next: usize = node + step;
+            if next < self.tree.len() && self.tree[next] <= remaining {
+                node = next;
+                remaining -= self.tree[next];
+            }
+            step >>= 1;
+        }
+        self.values[node]
+    }
+
+    pub fn quantile(&self, quantile: f64, interpolation: stats::Interpolation) -> f64 {
+        let (lower_idx, fraction) = stats::quantile_position(quantile, self.valid_count);
+        let lower: T = self.nth(lower_idx);
+        let upper: T = if fraction > 0.0 { self.nth(lower_idx + 1) } else { lower };
+        stats::interpolate(lower.to_f64(), upper.to_f64(), lower_idx, fraction, interpolation)
     }
 }
 
-pub struct Rank {
-    greater_count: usize,
-    equal_count: usize,
-    pub valid_count: usize,
+pub struct Quantile<T: Float> {
+    small_heap: Indexed<T>,
+    large_heap: Indexed<T>,
+    quantile: f64,
+    interpolation: stats::Interpolation,
 }
-impl Rank {
-    pub fn new() -> Self {
+
+impl<T: Float> Quantile<T> {
+    pub fn new(
+        capacity: usize,
+        max_idx: usize,
+        quantile: f64,
+        interpolation: stats::Interpolation
+    ) -> Self {
         Self {
-            greater_count: 0,
-            equal_count: 1,
-            valid_count: 1,
+            small_heap: Indexed::new(capacity, max_idx, true),
+            large_heap: Indexed::new(capacity, max_idx, false),
+            quantile,
+            interpolation,
         }
     }
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.small_heap.heap.len() + self.large_heap.heap.len()
+    }
 
-    pub fn add(&mut self, other: f64, current: f64) {
-        if other.is_nan() {
-            return;
+    pub fn push(&mut self, value: T, idx: usize) {
+        if let Some((max_small, _)) = self.small_heap.peek() {
+            if value > max_small {
+                self.large_heap.push(value, idx);
+            } else {
+                self.small_heap.push(value, idx);
+            }
+        } else {
+            self.small_heap.push(value, idx);
         }
-        self.valid_count += 1;
-        if current > other {
-            self.greater_count += 2;
-        } else if current == other {
-            self.equal_count += 1;
+        self.rebalance();
+    }
+
+    pub fn remove(&mut self, idx: usize) {
+        if !self.small_heap.remove(idx) {
+            self.large_heap.remove(idx);
         }
+        self.rebalance();
     }
 
-    pub fn get(&self) -> f64 {
-        stats::rank(self.greater_count, self.equal_count, self.valid_count as f64)
+    fn rebalance(&mut self) {
+        let target: usize = match self.len() {
+            0 => 0,
+            obs => stats::quantile_position(self.quantile, obs).0 + 1,
+        };
+
+        while self.small_heap.heap.len() > target {
+            if let Some((val, idx)) = self.small_heap.pop() {
+                self.large_heap.push(val, idx);
+            }
+        }
+
+        while self.small_heap.heap.len() < target {
+            if let Some((val, idx)) = self.large_heap.pop() {
+                self.small_heap.push(val, idx);
+            }
+        }
     }
-}
 
+    pub fn get(&self) -> Option<f64> {
+        let (lower, _) = self.small_heap.peek()?;
+        let (lower_idx, fraction) = stats::quantile_position(self.quantile, self.len());
+        let upper: T = match self.large_heap.peek() {
+            Some((val, _)) if fraction > 0.0 => val,
+            _ => lower,
+        };
+        Some(
+            stats::interpolate(
+                lower.to_f64(),
+                upper.to_f64(),
+                lower_idx,
+                fraction,
+                self.interpolation
+            )
+        )
+    }
+}
 
-pub struct Indexed {
-    pub heap: Vec<(f64, usize)>,
+pub struct Indexed<T: Float> {
+    pub heap: Vec<(T, usize)>,
     positions: Vec<Option<usize>>,
     is_max_heap: bool,
 }
 
-impl Indexed {
+impl<T: Float> Indexed<T> {
     pub fn new(capacity: usize, max_idx: usize, is_max_heap: bool) -> Self {
         Self {
             heap: Vec::with_capacity(capacity),
@@ -366,31 +1114,31 @@ impl Indexed {
     }
 
     #[inline(always)]
-    pub fn compare(&self, a: f64, b: f64) -> bool {
+    pub fn compare(&self, a: T, b: T) -> bool {
         let result: bool = a > b;
         result == self.is_max_heap
     }
     #[inline(always)]
-    pub fn peek(&self) -> Option<(f64, usize)> {
+    pub fn peek(&self) -> Option<(T, usize)> {
         self.heap.first().copied()
     }
 
-    pub fn push(&mut self, value: f64, idx: usize) {
+    pub fn push(&mut self, value: T, idx: usize) {
         let pos: usize = self.heap.len();
         self.heap.push((value, idx));
         self.positions[idx] = Some(pos);
         self.sift_up(pos);
     }
     #[inline(always)]
-    pub fn pop(&mut self) -> Option<(f64, usize)> {
+    pub fn pop(&mut self) -> Option<(T, usize)> {
         if self.heap.is_empty() {
             return None;
         }
 
-        let result: (f64, usize) = self.heap[0];
+        let result: (T, usize) = self.heap[0];
         self.positions[result.1] = None;
 
-        let last: (f64, usize) = self.heap.pop().unwrap();
+        let last: (T, usize) = self.heap.pop().unwrap();
         if !self.heap.is_empty() {
             self.heap[0] = last;
             self.positions[last.1] = Some(0);
@@ -407,7 +1155,7 @@ impl Indexed {
             if pos == self.heap.len() - 1 {
                 self.heap.pop();
             } else {
-                let last: (f64, usize) = self.heap.pop().unwrap();
+                let last: (T, usize) = self.heap.pop().unwrap();
                 self.heap[pos] = last;
                 self.positions[last.1] = Some(pos);
 
@@ -441,7 +1189,7 @@ impl Indexed {
     #[inline(always)]
     fn sift_down(&mut self, mut pos: usize) {
         let len: usize = self.heap.len();
-        let node_value: f64 = self.heap[pos].0;
+        let node_value: T = self.heap[pos].0;
         let node_idx: usize = self.heap[pos].1;
 
         loop {
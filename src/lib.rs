@@ -1,145 +1,1046 @@
-use numpy::{ PyArray2, PyReadonlyArray2 };
+use numpy::PyArrayDyn;
 use pyo3::prelude::*;
 mod stats;
 mod calculators;
 mod templates;
+mod validation;
+use templates::FloatArray;
 
 #[pyfunction]
+#[pyo3(signature = (array, length, min_length, parallel, axis = 0, center = false))]
 fn move_sum<'py>(
     py: Python<'py>,
-    array: PyReadonlyArray2<'py, f64>,
+    array: FloatArray<'py>,
     length: usize,
     min_length: usize,
-    parallel: bool
-) -> PyResult<Py<PyArray2<f64>>> {
-    templates::move_template::<calculators::Sum>(py, array, length, min_length, parallel)
+    parallel: bool,
+    axis: isize,
+    center: bool
+) -> PyResult<Py<PyAny>> {
+    templates::move_template::<calculators::Sum>(
+        py,
+        array,
+        length,
+        min_length,
+        parallel,
+        axis,
+        center
+    )
 }
 
 #[pyfunction]
+#[pyo3(signature = (array, length, min_length, parallel, axis = 0, center = false))]
 fn move_mean<'py>(
     py: Python<'py>,
-    array: PyReadonlyArray2<'py, f64>,
+    array: FloatArray<'py>,
     length: usize,
     min_length: usize,
-    parallel: bool
-) -> PyResult<Py<PyArray2<f64>>> {
-    templates::move_template::<calculators::Mean>(py, array, length, min_length, parallel)
+    parallel: bool,
+    axis: isize,
+    center: bool
+) -> PyResult<Py<PyAny>> {
+    templates::move_template::<calculators::Mean>(
+        py,
+        array,
+        length,
+        min_length,
+        parallel,
+        axis,
+        center
+    )
 }
 
 #[pyfunction]
+#[pyo3(signature = (array, length, min_length, parallel, axis = 0, center = false))]
 fn move_var<'py>(
     py: Python<'py>,
-    array: PyReadonlyArray2<'py, f64>,
+    array: FloatArray<'py>,
     length: usize,
     min_length: usize,
-    parallel: bool
-) -> PyResult<Py<PyArray2<f64>>> {
-    templates::move_template::<calculators::Var>(py, array, length, min_length, parallel)
+    parallel: bool,
+    axis: isize,
+    center: bool
+) -> PyResult<Py<PyAny>> {
+    templates::move_template::<calculators::Var>(
+        py,
+        array,
+        length,
+        min_length,
+        parallel,
+        axis,
+        center
+    )
 }
 
 #[pyfunction]
+#[pyo3(signature = (array, length, min_length, parallel, axis = 0, center = false))]
 fn move_std<'py>(
     py: Python<'py>,
-    array: PyReadonlyArray2<'py, f64>,
+    array: FloatArray<'py>,
     length: usize,
     min_length: usize,
-    parallel: bool
-) -> PyResult<Py<PyArray2<f64>>> {
-    templates::move_template::<calculators::Stdev>(py, array, length, min_length, parallel)
+    parallel: bool,
+    axis: isize,
+    center: bool
+) -> PyResult<Py<PyAny>> {
+    templates::move_template::<calculators::Stdev>(
+        py,
+        array,
+        length,
+        min_length,
+        parallel,
+        axis,
+        center
+    )
 }
 
 #[pyfunction]
+#[pyo3(signature = (array, length, min_length, parallel, axis = 0, center = false))]
 fn move_skewness<'py>(
     py: Python<'py>,
-    array: PyReadonlyArray2<'py, f64>,
+    array: FloatArray<'py>,
     length: usize,
     min_length: usize,
-    parallel: bool
-) -> PyResult<Py<PyArray2<f64>>> {
-    templates::move_template::<calculators::Skewness>(py, array, length, min_length, parallel)
+    parallel: bool,
+    axis: isize,
+    center: bool
+) -> PyResult<Py<PyAny>> {
+    templates::move_template::<calculators::Skewness>(
+        py,
+        array,
+        length,
+        min_length,
+        parallel,
+        axis,
+        center
+    )
 }
 
 #[pyfunction]
+#[pyo3(signature = (array, length, min_length, parallel, axis = 0, center = false))]
 fn move_kurtosis<'py>(
     py: Python<'py>,
-    array: PyReadonlyArray2<'py, f64>,
+    array: FloatArray<'py>,
     length: usize,
     min_length: usize,
-    parallel: bool
-) -> PyResult<Py<PyArray2<f64>>> {
-    templates::move_template::<calculators::Kurtosis>(py, array, length, min_length, parallel)
+    parallel: bool,
+    axis: isize,
+    center: bool
+) -> PyResult<Py<PyAny>> {
+    templates::move_template::<calculators::Kurtosis>(
+        py,
+        array,
+        length,
+        min_length,
+        parallel,
+        axis,
+        center
+    )
 }
 
 #[pyfunction]
+#[pyo3(signature = (array, length, min_length, parallel, axis = 0, center = false))]
 fn move_min<'py>(
     py: Python<'py>,
-    array: PyReadonlyArray2<'py, f64>,
+    array: FloatArray<'py>,
     length: usize,
     min_length: usize,
-    parallel: bool
-) -> PyResult<Py<PyArray2<f64>>> {
-    templates::move_deque_template::<calculators::Min>(py, array, length, min_length, parallel)
+    parallel: bool,
+    axis: isize,
+    center: bool
+) -> PyResult<Py<PyAny>> {
+    templates::move_deque_template::<calculators::Min>(
+        py,
+        array,
+        length,
+        min_length,
+        parallel,
+        axis,
+        center
+    )
 }
 
 #[pyfunction]
+#[pyo3(signature = (array, length, min_length, parallel, axis = 0, center = false))]
+fn move_argmin<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    length: usize,
+    min_length: usize,
+    parallel: bool,
+    axis: isize,
+    center: bool
+) -> PyResult<Py<PyAny>> {
+    templates::move_arg_template::<calculators::Min>(
+        py,
+        array,
+        length,
+        min_length,
+        parallel,
+        axis,
+        center
+    )
+}
+
+#[pyfunction]
+#[pyo3(signature = (array, length, min_length, parallel, axis = 0, center = false))]
 fn move_max<'py>(
     py: Python<'py>,
-    array: PyReadonlyArray2<'py, f64>,
+    array: FloatArray<'py>,
+    length: usize,
+    min_length: usize,
+    parallel: bool,
+    axis: isize,
+    center: bool
+) -> PyResult<Py<PyAny>> {
+    templates::move_deque_template::<calculators::Max>(
+        py,
+        array,
+        length,
+        min_length,
+        parallel,
+        axis,
+        center
+    )
+}
+
+#[pyfunction]
+#[pyo3(signature = (array, length, min_length, parallel, axis = 0, center = false))]
+fn move_argmax<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    length: usize,
+    min_length: usize,
+    parallel: bool,
+    axis: isize,
+    center: bool
+) -> PyResult<Py<PyAny>> {
+    templates::move_arg_template::<calculators::Max>(
+        py,
+        array,
+        length,
+        min_length,
+        parallel,
+        axis,
+        center
+    )
+}
+
+#[pyfunction]
+#[pyo3(signature = (a, b, length, min_length, parallel, axis = 0, center = false))]
+#[allow(clippy::too_many_arguments)]
+fn move_cov<'py>(
+    py: Python<'py>,
+    a: FloatArray<'py>,
+    b: FloatArray<'py>,
+    length: usize,
+    min_length: usize,
+    parallel: bool,
+    axis: isize,
+    center: bool
+) -> PyResult<Py<PyAny>> {
+    templates::move_pair_template::<calculators::Cov>(
+        py,
+        a,
+        b,
+        length,
+        min_length,
+        parallel,
+        axis,
+        center
+    )
+}
+
+#[pyfunction]
+#[pyo3(signature = (a, b, length, min_length, parallel, axis = 0, center = false))]
+#[allow(clippy::too_many_arguments)]
+fn move_corr<'py>(
+    py: Python<'py>,
+    a: FloatArray<'py>,
+    b: FloatArray<'py>,
+    length: usize,
+    min_length: usize,
+    parallel: bool,
+    axis: isize,
+    center: bool
+) -> PyResult<Py<PyAny>> {
+    templates::move_pair_template::<calculators::Corr>(
+        py,
+        a,
+        b,
+        length,
+        min_length,
+        parallel,
+        axis,
+        center
+    )
+}
+
+#[pyfunction]
+#[pyo3(signature = (array, target, length, min_length, parallel, axis = 0, center = false))]
+#[allow(clippy::too_many_arguments)]
+fn move_cov_with<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    target: FloatArray<'py>,
+    length: usize,
+    min_length: usize,
+    parallel: bool,
+    axis: isize,
+    center: bool
+) -> PyResult<Py<PyAny>> {
+    templates::move_with_template::<calculators::Cov>(
+        py,
+        array,
+        target,
+        length,
+        min_length,
+        parallel,
+        axis,
+        center
+    )
+}
+
+#[pyfunction]
+#[pyo3(signature = (array, target, length, min_length, parallel, axis = 0, center = false))]
+#[allow(clippy::too_many_arguments)]
+fn move_corr_with<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    target: FloatArray<'py>,
+    length: usize,
+    min_length: usize,
+    parallel: bool,
+    axis: isize,
+    center: bool
+) -> PyResult<Py<PyAny>> {
+    templates::move_with_template::<calculators::Corr>(
+        py,
+        array,
+        target,
+        length,
+        min_length,
+        parallel,
+        axis,
+        center
+    )
+}
+
+#[pyfunction]
+#[pyo3(signature = (array, market, length, min_length, parallel, axis = 0, center = false))]
+#[allow(clippy::too_many_arguments)]
+fn move_beta<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    market: FloatArray<'py>,
     length: usize,
     min_length: usize,
-    parallel: bool
-) -> PyResult<Py<PyArray2<f64>>> {
-    templates::move_deque_template::<calculators::Max>(py, array, length, min_length, parallel)
+    parallel: bool,
+    axis: isize,
+    center: bool
+) -> PyResult<Py<PyAny>> {
+    templates::move_with_template::<calculators::Beta>(
+        py,
+        array,
+        market,
+        length,
+        min_length,
+        parallel,
+        axis,
+        center
+    )
 }
 
 #[pyfunction]
-fn agg_sum<'py>() -> PyResult<Py<PyArray2<f64>>> {
-    todo!()
+#[pyo3(signature = (array, length, min_length, parallel, axis = 0, center = false))]
+fn move_cov_matrix<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    length: usize,
+    min_length: usize,
+    parallel: bool,
+    axis: isize,
+    center: bool
+) -> PyResult<Py<PyAny>> {
+    templates::move_matrix_template::<calculators::Cov>(
+        py,
+        array,
+        length,
+        min_length,
+        parallel,
+        axis,
+        center
+    )
 }
 
 #[pyfunction]
-fn agg_mean<'py>() -> PyResult<Py<PyArray2<f64>>> {
-    todo!()
+#[pyo3(signature = (array, length, min_length, parallel, axis = 0, center = false))]
+fn move_corr_matrix<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    length: usize,
+    min_length: usize,
+    parallel: bool,
+    axis: isize,
+    center: bool
+) -> PyResult<Py<PyAny>> {
+    templates::move_matrix_template::<calculators::Corr>(
+        py,
+        array,
+        length,
+        min_length,
+        parallel,
+        axis,
+        center
+    )
 }
 
 #[pyfunction]
-fn agg_var<'py>() -> PyResult<Py<PyArray2<f64>>> {
-    todo!()
+#[pyo3(
+    signature = (
+        array,
+        min_length,
+        parallel,
+        com = None,
+        span = None,
+        halflife = None,
+        alpha = None,
+        adjust = true,
+        ignore_na = false,
+        axis = 0,
+    )
+)]
+#[allow(clippy::too_many_arguments)]
+fn ewm_mean<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    min_length: usize,
+    parallel: bool,
+    com: Option<f64>,
+    span: Option<f64>,
+    halflife: Option<f64>,
+    alpha: Option<f64>,
+    adjust: bool,
+    ignore_na: bool,
+    axis: isize
+) -> PyResult<Py<PyAny>> {
+    let alpha: f64 = validation::get_alpha(com, span, halflife, alpha)?;
+    templates::ewm_template::<calculators::EwmMean>(
+        py,
+        array,
+        min_length,
+        alpha,
+        adjust,
+        ignore_na,
+        parallel,
+        axis
+    )
 }
 
 #[pyfunction]
-fn agg_std<'py>() -> PyResult<Py<PyArray2<f64>>> {
-    todo!()
+#[pyo3(
+    signature = (
+        array,
+        min_length,
+        parallel,
+        com = None,
+        span = None,
+        halflife = None,
+        alpha = None,
+        adjust = true,
+        ignore_na = false,
+        axis = 0,
+    )
+)]
+#[allow(clippy::too_many_arguments)]
+fn ewm_var<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    min_length: usize,
+    parallel: bool,
+    com: Option<f64>,
+    span: Option<f64>,
+    halflife: Option<f64>,
+    alpha: Option<f64>,
+    adjust: bool,
+    ignore_na: bool,
+    axis: isize
+) -> PyResult<Py<PyAny>> {
+    let alpha: f64 = validation::get_alpha(com, span, halflife, alpha)?;
+    templates::ewm_template::<calculators::EwmVar>(
+        py,
+        array,
+        min_length,
+        alpha,
+        adjust,
+        ignore_na,
+        parallel,
+        axis
+    )
 }
 
 #[pyfunction]
-fn agg_skewness<'py>() -> PyResult<Py<PyArray2<f64>>> {
-    todo!()
+#[pyo3(
+    signature = (
+        array,
+        min_length,
+        parallel,
+        com = None,
+        span = None,
+        halflife = None,
+        alpha = None,
+        adjust = true,
+        ignore_na = false,
+        axis = 0,
+    )
+)]
+#[allow(clippy::too_many_arguments)]
+fn ewm_std<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    min_length: usize,
+    parallel: bool,
+    com: Option<f64>,
+    span: Option<f64>,
+    halflife: Option<f64>,
+    alpha: Option<f64>,
+    adjust: bool,
+    ignore_na: bool,
+    axis: isize
+) -> PyResult<Py<PyAny>> {
+    let alpha: f64 = validation::get_alpha(com, span, halflife, alpha)?;
+    templates::ewm_template::<calculators::EwmStd>(
+        py,
+        array,
+        min_length,
+        alpha,
+        adjust,
+        ignore_na,
+        parallel,
+        axis
+    )
 }
 
 #[pyfunction]
-fn agg_kurtosis<'py>() -> PyResult<Py<PyArray2<f64>>> {
-    todo!()
+#[pyo3(
+    signature = (
+        a,
+        b,
+        min_length,
+        parallel,
+        com = None,
+        span = None,
+        halflife = None,
+        alpha = None,
+        adjust = true,
+        ignore_na = false,
+        axis = 0,
+    )
+)]
+#[allow(clippy::too_many_arguments)]
+fn ewm_cov<'py>(
+    py: Python<'py>,
+    a: FloatArray<'py>,
+    b: FloatArray<'py>,
+    min_length: usize,
+    parallel: bool,
+    com: Option<f64>,
+    span: Option<f64>,
+    halflife: Option<f64>,
+    alpha: Option<f64>,
+    adjust: bool,
+    ignore_na: bool,
+    axis: isize
+) -> PyResult<Py<PyAny>> {
+    let alpha: f64 = validation::get_alpha(com, span, halflife, alpha)?;
+    templates::ewm_pair_template::<calculators::EwmCov>(
+        py,
+        a,
+        b,
+        min_length,
+        alpha,
+        adjust,
+        ignore_na,
+        parallel,
+        axis
+    )
 }
 
 #[pyfunction]
-fn agg_min<'py>() -> PyResult<Py<PyArray2<f64>>> {
-    todo!()
+#[pyo3(
+    signature = (
+        a,
+        b,
+        min_length,
+        parallel,
+        com = None,
+        span = None,
+        halflife = None,
+        alpha = None,
+        adjust = true,
+        ignore_na = false,
+        axis = 0,
+    )
+)]
+#[allow(clippy::too_many_arguments)]
+fn ewm_corr<'py>(
+    py: Python<'py>,
+    a: FloatArray<'py>,
+    b: FloatArray<'py>,
+    min_length: usize,
+    parallel: bool,
+    com: Option<f64>,
+    span: Option<f64>,
+    halflife: Option<f64>,
+    alpha: Option<f64>,
+    adjust: bool,
+    ignore_na: bool,
+    axis: isize
+) -> PyResult<Py<PyAny>> {
+    let alpha: f64 = validation::get_alpha(com, span, halflife, alpha)?;
+    templates::ewm_pair_template::<calculators::EwmCorr>(
+        py,
+        a,
+        b,
+        min_length,
+        alpha,
+        adjust,
+        ignore_na,
+        parallel,
+        axis
+    )
 }
 
 #[pyfunction]
-fn agg_max<'py>() -> PyResult<Py<PyArray2<f64>>> {
-    todo!()
+#[pyo3(
+    signature = (
+        array,
+        min_length,
+        parallel,
+        com = None,
+        span = None,
+        halflife = None,
+        alpha = None,
+        adjust = true,
+        ignore_na = false,
+        axis = 0,
+    )
+)]
+#[allow(clippy::too_many_arguments)]
+fn ewm_cov_matrix<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    min_length: usize,
+    parallel: bool,
+    com: Option<f64>,
+    span: Option<f64>,
+    halflife: Option<f64>,
+    alpha: Option<f64>,
+    adjust: bool,
+    ignore_na: bool,
+    axis: isize
+) -> PyResult<Py<PyAny>> {
+    let alpha: f64 = validation::get_alpha(com, span, halflife, alpha)?;
+    templates::ewm_matrix_template::<calculators::EwmCov>(
+        py,
+        array,
+        min_length,
+        alpha,
+        adjust,
+        ignore_na,
+        parallel,
+        axis
+    )
+}
+
+#[pyfunction]
+#[pyo3(signature = (array, min_length, parallel, axis = 0))]
+fn cum_sum<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    min_length: usize,
+    parallel: bool,
+    axis: isize
+) -> PyResult<Py<PyAny>> {
+    templates::move_template::<calculators::Sum>(
+        py,
+        array,
+        templates::EXPANDING_LENGTH,
+        min_length,
+        parallel,
+        axis,
+        false
+    )
 }
 
 #[pyfunction]
-fn agg_median<'py>() -> PyResult<Py<PyArray2<f64>>> {
-    todo!()
+#[pyo3(signature = (array, min_length, parallel, axis = 0))]
+fn cum_prod<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    min_length: usize,
+    parallel: bool,
+    axis: isize
+) -> PyResult<Py<PyAny>> {
+    templates::move_template::<calculators::Product>(
+        py,
+        array,
+        templates::EXPANDING_LENGTH,
+        min_length,
+        parallel,
+        axis,
+        false
+    )
 }
 
 #[pyfunction]
-fn agg_rank<'py>() -> PyResult<Py<PyArray2<f64>>> {
-    todo!()
+#[pyo3(signature = (array, min_length, parallel, axis = 0))]
+fn cum_max<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    min_length: usize,
+    parallel: bool,
+    axis: isize
+) -> PyResult<Py<PyAny>> {
+    templates::move_deque_template::<calculators::Max>(
+        py,
+        array,
+        templates::EXPANDING_LENGTH,
+        min_length,
+        parallel,
+        axis,
+        false
+    )
+}
+
+#[pyfunction]
+#[pyo3(signature = (array, min_length, parallel, axis = 0))]
+fn cum_min<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    min_length: usize,
+    parallel: bool,
+    axis: isize
+) -> PyResult<Py<PyAny>> {
+    templates::move_deque_template::<calculators::Min>(
+        py,
+        array,
+        templates::EXPANDING_LENGTH,
+        min_length,
+        parallel,
+        axis,
+        false
+    )
+}
+
+#[pyfunction]
+#[pyo3(signature = (array, min_length, parallel, axis = 0))]
+fn expanding_mean<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    min_length: usize,
+    parallel: bool,
+    axis: isize
+) -> PyResult<Py<PyAny>> {
+    templates::move_template::<calculators::Mean>(
+        py,
+        array,
+        templates::EXPANDING_LENGTH,
+        min_length,
+        parallel,
+        axis,
+        false
+    )
+}
+
+#[pyfunction]
+#[pyo3(signature = (array, min_length, parallel, axis = 0))]
+fn expanding_var<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    min_length: usize,
+    parallel: bool,
+    axis: isize
+) -> PyResult<Py<PyAny>> {
+    templates::move_template::<calculators::Var>(
+        py,
+        array,
+        templates::EXPANDING_LENGTH,
+        min_length,
+        parallel,
+        axis,
+        false
+    )
+}
+
+#[pyfunction]
+#[pyo3(signature = (array, min_length, parallel, axis = 0))]
+fn expanding_std<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    min_length: usize,
+    parallel: bool,
+    axis: isize
+) -> PyResult<Py<PyAny>> {
+    templates::move_template::<calculators::Stdev>(
+        py,
+        array,
+        templates::EXPANDING_LENGTH,
+        min_length,
+        parallel,
+        axis,
+        false
+    )
+}
+
+#[pyfunction]
+#[pyo3(signature = (array, min_length, parallel, axis = 0))]
+fn expanding_skewness<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    min_length: usize,
+    parallel: bool,
+    axis: isize
+) -> PyResult<Py<PyAny>> {
+    templates::move_template::<calculators::Skewness>(
+        py,
+        array,
+        templates::EXPANDING_LENGTH,
+        min_length,
+        parallel,
+        axis,
+        false
+    )
+}
+
+#[pyfunction]
+#[pyo3(signature = (array, min_length, parallel, axis = 0))]
+fn expanding_kurtosis<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    min_length: usize,
+    parallel: bool,
+    axis: isize
+) -> PyResult<Py<PyAny>> {
+    templates::move_template::<calculators::Kurtosis>(
+        py,
+        array,
+        templates::EXPANDING_LENGTH,
+        min_length,
+        parallel,
+        axis,
+        false
+    )
+}
+
+#[pyfunction]
+#[pyo3(
+    signature = (
+        array,
+        weights,
+        min_length,
+        parallel,
+        axis = 0,
+        center = false,
+        length = None,
+        std = None,
+    )
+)]
+#[allow(clippy::too_many_arguments)]
+fn move_weighted_sum<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    weights: templates::Weights,
+    min_length: usize,
+    parallel: bool,
+    axis: isize,
+    center: bool,
+    length: Option<usize>,
+    std: Option<f64>
+) -> PyResult<Py<PyAny>> {
+    templates::move_weighted_template(
+        py,
+        array,
+        weights,
+        min_length,
+        false,
+        parallel,
+        axis,
+        center,
+        length,
+        std
+    )
+}
+
+#[pyfunction]
+#[pyo3(
+    signature = (
+        array,
+        weights,
+        min_length,
+        parallel,
+        axis = 0,
+        center = false,
+        length = None,
+        std = None,
+    )
+)]
+#[allow(clippy::too_many_arguments)]
+fn move_weighted_mean<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    weights: templates::Weights,
+    min_length: usize,
+    parallel: bool,
+    axis: isize,
+    center: bool,
+    length: Option<usize>,
+    std: Option<f64>
+) -> PyResult<Py<PyAny>> {
+    templates::move_weighted_template(
+        py,
+        array,
+        weights,
+        min_length,
+        true,
+        parallel,
+        axis,
+        center,
+        length,
+        std
+    )
+}
+
+#[pyfunction]
+#[pyo3(signature = (array, length, min_length, parallel, axis = 0, center = false))]
+fn move_wma<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    length: usize,
+    min_length: usize,
+    parallel: bool,
+    axis: isize,
+    center: bool
+) -> PyResult<Py<PyAny>> {
+    templates::move_template::<calculators::Wma>(
+        py,
+        array,
+        length,
+        min_length,
+        parallel,
+        axis,
+        center
+    )
+}
+
+#[pyfunction]
+#[pyo3(signature = (array, parallel, axis = 0))]
+fn agg_sum<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    parallel: bool,
+    axis: isize
+) -> PyResult<Py<PyAny>> {
+    templates::agg_template::<calculators::Sum>(py, array, parallel, axis)
+}
+
+#[pyfunction]
+#[pyo3(signature = (array, parallel, axis = 0))]
+fn agg_mean<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    parallel: bool,
+    axis: isize
+) -> PyResult<Py<PyAny>> {
+    templates::agg_template::<calculators::Mean>(py, array, parallel, axis)
+}
+
+#[pyfunction]
+#[pyo3(signature = (array, parallel, axis = 0))]
+fn agg_var<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    parallel: bool,
+    axis: isize
+) -> PyResult<Py<PyAny>> {
+    templates::agg_template::<calculators::Var>(py, array, parallel, axis)
+}
+
+#[pyfunction]
+#[pyo3(signature = (array, parallel, axis = 0))]
+fn agg_std<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    parallel: bool,
+    axis: isize
+) -> PyResult<Py<PyAny>> {
+    templates::agg_template::<calculators::Stdev>(py, array, parallel, axis)
+}
+
+#[pyfunction]
+#[pyo3(signature = (array, parallel, axis = 0))]
+fn agg_skewness<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    parallel: bool,
+    axis: isize
+) -> PyResult<Py<PyAny>> {
+    templates::agg_template::<calculators::Skewness>(py, array, parallel, axis)
+}
+
+#[pyfunction]
+#[pyo3(signature = (array, parallel, axis = 0))]
+fn agg_kurtosis<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    parallel: bool,
+    axis: isize
+) -> PyResult<Py<PyAny>> {
+    templates::agg_template::<calculators::Kurtosis>(py, array, parallel, axis)
+}
+
+#[pyfunction]
+#[pyo3(signature = (array, parallel, axis = 0))]
+fn agg_min<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    parallel: bool,
+    axis: isize
+) -> PyResult<Py<PyAny>> {
+    templates::agg_deque_template::<calculators::Min>(py, array, parallel, axis)
+}
+
+#[pyfunction]
+#[pyo3(signature = (array, parallel, axis = 0))]
+fn agg_argmin<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    parallel: bool,
+    axis: isize
+) -> PyResult<Py<PyArrayDyn<i64>>> {
+    templates::agg_arg_template::<calculators::Min>(py, array, parallel, axis)
+}
+
+#[pyfunction]
+#[pyo3(signature = (array, parallel, axis = 0))]
+fn agg_max<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    parallel: bool,
+    axis: isize
+) -> PyResult<Py<PyAny>> {
+    templates::agg_deque_template::<calculators::Max>(py, array, parallel, axis)
+}
+
+#[pyfunction]
+#[pyo3(signature = (array, parallel, axis = 0))]
+fn agg_argmax<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    parallel: bool,
+    axis: isize
+) -> PyResult<Py<PyArrayDyn<i64>>> {
+    templates::agg_arg_template::<calculators::Max>(py, array, parallel, axis)
 }
 
 #[pymodule(name = "rustats")]
@@ -150,19 +1051,55 @@ fn rustats(module: &Bound<'_, PyModule>) -> PyResult<()> {
     module.add_function(wrap_pyfunction!(move_mean, module)?)?;
     module.add_function(wrap_pyfunction!(move_max, module)?)?;
     module.add_function(wrap_pyfunction!(move_min, module)?)?;
+    module.add_function(wrap_pyfunction!(move_argmax, module)?)?;
+    module.add_function(wrap_pyfunction!(move_argmin, module)?)?;
     module.add_function(wrap_pyfunction!(templates::move_median, module)?)?;
+    module.add_function(wrap_pyfunction!(templates::move_quantile, module)?)?;
+    module.add_function(wrap_pyfunction!(templates::move_quantiles, module)?)?;
     module.add_function(wrap_pyfunction!(move_skewness, module)?)?;
     module.add_function(wrap_pyfunction!(move_kurtosis, module)?)?;
     module.add_function(wrap_pyfunction!(templates::move_rank, module)?)?;
+    module.add_function(wrap_pyfunction!(move_cov, module)?)?;
+    module.add_function(wrap_pyfunction!(move_corr, module)?)?;
+    module.add_function(wrap_pyfunction!(move_cov_with, module)?)?;
+    module.add_function(wrap_pyfunction!(move_corr_with, module)?)?;
+    module.add_function(wrap_pyfunction!(move_beta, module)?)?;
+    module.add_function(wrap_pyfunction!(move_cov_matrix, module)?)?;
+    module.add_function(wrap_pyfunction!(move_corr_matrix, module)?)?;
+    module.add_function(wrap_pyfunction!(templates::move_linreg, module)?)?;
+    module.add_function(wrap_pyfunction!(templates::move_trend, module)?)?;
+    module.add_function(wrap_pyfunction!(templates::move_ols, module)?)?;
+    module.add_function(wrap_pyfunction!(move_weighted_sum, module)?)?;
+    module.add_function(wrap_pyfunction!(move_weighted_mean, module)?)?;
+    module.add_function(wrap_pyfunction!(move_wma, module)?)?;
+    module.add_function(wrap_pyfunction!(templates::get_window, module)?)?;
+    module.add_function(wrap_pyfunction!(ewm_mean, module)?)?;
+    module.add_function(wrap_pyfunction!(ewm_var, module)?)?;
+    module.add_function(wrap_pyfunction!(ewm_std, module)?)?;
+    module.add_function(wrap_pyfunction!(ewm_cov, module)?)?;
+    module.add_function(wrap_pyfunction!(ewm_corr, module)?)?;
+    module.add_function(wrap_pyfunction!(ewm_cov_matrix, module)?)?;
+    module.add_function(wrap_pyfunction!(cum_sum, module)?)?;
+    module.add_function(wrap_pyfunction!(cum_prod, module)?)?;
+    module.add_function(wrap_pyfunction!(cum_max, module)?)?;
+    module.add_function(wrap_pyfunction!(cum_min, module)?)?;
+    module.add_function(wrap_pyfunction!(expanding_mean, module)?)?;
+    module.add_function(wrap_pyfunction!(expanding_var, module)?)?;
+    module.add_function(wrap_pyfunction!(expanding_std, module)?)?;
+    module.add_function(wrap_pyfunction!(expanding_skewness, module)?)?;
+    module.add_function(wrap_pyfunction!(expanding_kurtosis, module)?)?;
+    module.add_function(wrap_pyfunction!(templates::expanding_median, module)?)?;
     module.add_function(wrap_pyfunction!(agg_sum, module)?)?;
     module.add_function(wrap_pyfunction!(agg_std, module)?)?;
     module.add_function(wrap_pyfunction!(agg_var, module)?)?;
     module.add_function(wrap_pyfunction!(agg_mean, module)?)?;
     module.add_function(wrap_pyfunction!(agg_max, module)?)?;
     module.add_function(wrap_pyfunction!(agg_min, module)?)?;
+    module.add_function(wrap_pyfunction!(agg_argmax, module)?)?;
+    module.add_function(wrap_pyfunction!(agg_argmin, module)?)?;
     module.add_function(wrap_pyfunction!(agg_skewness, module)?)?;
     module.add_function(wrap_pyfunction!(agg_kurtosis, module)?)?;
-    module.add_function(wrap_pyfunction!(agg_median, module)?)?;
-    module.add_function(wrap_pyfunction!(agg_rank, module)?)?;
+    module.add_function(wrap_pyfunction!(templates::agg_median, module)?)?;
+    module.add_function(wrap_pyfunction!(templates::agg_rank, module)?)?;
     Ok(())
 }
@@ -1,19 +1,50 @@
 #[inline(always)]
-pub fn var(sum_simple: f64, sum_squared: f64, obs: f64) -> f64 {
-    (sum_squared / obs - (sum_simple / obs).powi(2)) * (obs / (obs - 1.0))
+pub fn var(m2: f64, obs: f64) -> f64 {
+    let m2: f64 = if m2 < 0.0 { 0.0 } else { m2 };
+    m2 / (obs - 1.0)
 }
 
 #[inline(always)]
-pub fn stdev(sum_simple: f64, sum_squared: f64, obs: f64) -> f64 {
-    var(sum_simple, sum_squared, obs).sqrt()
+pub fn stdev(m2: f64, obs: f64) -> f64 {
+    var(m2, obs).sqrt()
 }
 
 #[inline(always)]
-pub fn skew(sum_simple: f64, sum_squared: f64, sum_cubed: f64, obs: f64) -> f64 {
-    let mean_value: f64 = sum_simple / obs;
-    let variance_value: f64 = var(sum_simple, sum_squared, obs);
-    let skew_numerator: f64 =
-        sum_cubed / obs - mean_value.powi(3) - 3.0 * mean_value * variance_value;
+pub fn cov(cxy: f64, obs: f64) -> f64 {
+    cxy / (obs - 1.0)
+}
+
+#[inline(always)]
+pub fn corr(m2_x: f64, m2_y: f64, cxy: f64) -> f64 {
+    let denominator: f64 = (m2_x * m2_y).sqrt();
+    if denominator.is_nan() || denominator <= 0.0 {
+        return f64::NAN;
+    }
+    (cxy / denominator).clamp(-1.0, 1.0)
+}
+
+#[inline(always)]
+pub fn beta(m2_y: f64, cxy: f64) -> f64 {
+    if m2_y <= 0.0 {
+        return f64::NAN;
+    }
+    cxy / m2_y
+}
+
+#[inline(always)]
+pub fn ewm_cov(cov: f64, sum_weights: f64, sum_squared_weights: f64) -> f64 {
+    let numerator: f64 = sum_weights * sum_weights;
+    let denominator: f64 = numerator - sum_squared_weights;
+    if denominator <= 0.0 {
+        return f64::NAN;
+    }
+    (numerator / denominator) * cov
+}
+
+#[inline(always)]
+pub fn skew(m2: f64, m3: f64, obs: f64) -> f64 {
+    let variance_value: f64 = var(m2, obs);
+    let skew_numerator: f64 = m3 / obs;
 
     let std_dev: f64 = variance_value.sqrt();
 
@@ -22,17 +53,9 @@ pub fn skew(sum_simple: f64, sum_squared: f64, sum_cubed: f64, obs: f64) -> f64
 }
 
 #[inline(always)]
-pub fn kurtosis(sum_simple: f64, sum_squared: f64, sum_cubed: f64, sum_quad: f64, obs: f64) -> f64 {
-    let mean_value: f64 = sum_simple / obs;
-    let variance_value: f64 = var(sum_simple, sum_squared, obs);
-    let skew_numerator: f64 =
-        sum_cubed / obs - mean_value.powi(3) - 3.0 * mean_value * variance_value;
-
-    let kurtosis_term: f64 =
-        sum_quad / obs -
-        mean_value.powi(4) -
-        6.0 * variance_value * mean_value.powi(2) -
-        4.0 * skew_numerator * mean_value;
+pub fn kurtosis(m2: f64, m4: f64, obs: f64) -> f64 {
+    let variance_value: f64 = var(m2, obs);
+    let kurtosis_term: f64 = m4 / obs;
 
     let kurt: f64 =
         (((obs * obs - 1.0) * kurtosis_term) / variance_value.powi(2) - 3.0 * (obs - 1.0).powi(2)) /
@@ -46,3 +69,129 @@ pub fn rank(greater_count: usize, equal_count: usize, obs: f64) -> f64 {
     let normalized_rank: f64 = 2.0 * ((0.5 * raw_rank) / (obs - 1.0) - 0.5);
     normalized_rank
 }
+
+const CHOLESKY_TOLERANCE: f64 = 1e-12;
+
+pub fn cholesky_solve(
+    matrix: &[f64],
+    rhs: &[f64],
+    ridge: f64,
+    factor: &mut [f64],
+    solution: &mut [f64]
+) -> bool {
+    let size: usize = rhs.len();
+    for i in 0..size {
+        for j in 0..=i {
+            let mut sum: f64 = matrix[i * size + j];
+            for p in 0..j {
+                sum -= factor[i * size + p] * factor[j * size + p];
+            }
+            if i == j {
+                let diagonal: f64 = matrix[i * size + i] + ridge;
+                sum += ridge;
+                if sum.is_nan() || sum <= CHOLESKY_TOLERANCE * diagonal {
+                    return false;
+                }
+                factor[i * size + i] = sum.sqrt();
+            } else {
+                factor[i * size + j] = sum / factor[j * size + j];
+            }
+        }
+    }
+
+    for i in 0..size {
+        let mut sum: f64 = rhs[i];
+        for p in 0..i {
+            sum -= factor[i * size + p] * solution[p];
+        }
+        solution[i] = sum / factor[i * size + i];
+    }
+    for i in (0..size).rev() {
+        let mut sum: f64 = solution[i];
+        for p in i + 1..size {
+            sum -= factor[p * size + i] * solution[p];
+        }
+        solution[i] = sum / factor[i * size + i];
+    }
+    true
+}
+
+#[derive(Clone, Copy)]
+pub enum Interpolation {
+    Linear,
+    Lower,
+    Higher,
+    Nearest,
+    Midpoint,
+}
+
+#[inline(always)]
+pub fn quantile_position(quantile: f64, obs: usize) -> (usize, f64) {
+    let position: f64 = quantile * ((obs - 1) as f64);
+    let lower_idx: f64 = position.floor();
+    (lower_idx as usize, position - lower_idx)
+}
+
+#[inline(always)]
+pub fn interpolate(
+    lower: f64,
+    upper: f64,
+    lower_idx: usize,
+    fraction: f64,
+    interpolation: Interpolation
+) -> f64 {
+    if fraction == 0.0 {
+        return lower;
+    }
+    match interpolation {
+        Interpolation::Linear => lower + (upper - lower) * fraction,
+        Interpolation::Lower => lower,
+        Interpolation::Higher => upper,
+        Interpolation::Nearest => {
+            let is_even: bool = lower_idx.is_multiple_of(2);
+            let round_down: bool = fraction < 0.5 || (fraction == 0.5 && is_even);
+            if round_down { lower } else { upper }
+        }
+        Interpolation::Midpoint => (lower + upper) / 2.0,
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum WindowType {
+    Boxcar,
+    Triang,
+    Bartlett,
+    Hann,
+    Hamming,
+    Blackman,
+    Gaussian(f64),
+}
+
+pub fn window_weights(window_type: WindowType, length: usize) -> Vec<f64> {
+    if length == 1 {
+        return vec![1.0];
+    }
+    let last: f64 = (length - 1) as f64;
+    (0..length)
+        .map(|idx| {
+            let n: f64 = idx as f64;
+            let phase: f64 = (2.0 * std::f64::consts::PI * n) / last;
+            match window_type {
+                WindowType::Boxcar => 1.0,
+                WindowType::Triang => {
+                    let half_width: f64 = if length.is_multiple_of(2) {
+                        (length as f64) / 2.0
+                    } else {
+                        ((length + 1) as f64) / 2.0
+                    };
+                    1.0 - (n - last / 2.0).abs() / half_width
+                }
+                WindowType::Bartlett => 1.0 - (2.0 * n - last).abs() / last,
+                WindowType::Hann => 0.5 - 0.5 * phase.cos(),
+                WindowType::Hamming => 0.54 - 0.46 * phase.cos(),
+                WindowType::Blackman => 0.42 - 0.5 * phase.cos() + 0.08 * (2.0 * phase).cos(),
+                WindowType::Gaussian(std) => (-0.5 * ((n - last / 2.0) / std).powi(2)).exp(),
+            }
+        })
+        .collect()
+}
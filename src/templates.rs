@@ -1,148 +1,1563 @@
-use numpy::{ PyArray2, PyReadonlyArray2, IntoPyArray };
+use numpy::{ PyArray1, PyArrayDyn, PyReadonlyArrayDyn, IntoPyArray };
 use pyo3::prelude::*;
-use numpy::ndarray::{ Array2, ArrayView1, ArrayViewMut1 };
+use pyo3::exceptions::PyTypeError;
+use numpy::ndarray::{
+    s,
+    Array1,
+    Array3,
+    ArrayD,
+    ArrayView1,
+    ArrayView2,
+    ArrayViewD,
+    ArrayViewMut1,
+    ArrayViewMut2,
+    Axis,
+    CowArray,
+    Ix1,
+    Ix2,
+    IxDyn,
+};
 use rayon::prelude::*;
-use crate::calculators;
-use std::collections::VecDeque;
+use crate::calculators::{ self, Float };
+use crate::stats;
+use crate::validation;
+
+#[derive(FromPyObject)]
+pub enum FloatArray<'py> {
+    F64(PyReadonlyArrayDyn<'py, f64>),
+    F32(PyReadonlyArrayDyn<'py, f32>),
+}
+
+#[derive(FromPyObject)]
+pub enum Weights {
+    Named(String),
+    Values(Vec<f64>),
+}
+
+const LINREG_OUTPUTS: usize = 4;
+pub const EXPANDING_LENGTH: usize = usize::MAX;
+
+macro_rules! dispatch {
+    ($array:ident, $float:ident => $body:block) => {
+        match $array {
+            FloatArray::F64($array) => {
+                type $float = f64;
+                $body
+            }
+            FloatArray::F32($array) => {
+                type $float = f32;
+                $body
+            }
+        }
+    };
+}
+
+macro_rules! dispatch_pair {
+    ($left:ident, $right:ident, $float:ident => $body:block) => {
+        match ($left, $right) {
+            (FloatArray::F64($left), FloatArray::F64($right)) => {
+                type $float = f64;
+                $body
+            }
+            (FloatArray::F32($left), FloatArray::F32($right)) => {
+                type $float = f32;
+                $body
+            }
+            _ => Err(PyTypeError::new_err("both arrays must have the same dtype")),
+        }
+    };
+}
 
 #[pyfunction]
+#[pyo3(signature = (array, length, min_length, parallel, axis = 0, center = false))]
 pub fn move_median<'py>(
     py: Python<'py>,
-    array: PyReadonlyArray2<'py, f64>,
+    array: FloatArray<'py>,
     length: usize,
     min_length: usize,
-    parallel: bool
-) -> PyResult<Py<PyArray2<f64>>> {
-    let array = array.as_array();
-    let (num_rows, num_cols) = array.dim();
-    let mut output = Array2::<f64>::from_elem((num_rows, num_cols), f64::NAN);
-    let input_columns: Vec<_> = array.columns().into_iter().collect();
-    let mut output_columns: Vec<_> = output.columns_mut().into_iter().collect();
+    parallel: bool,
+    axis: isize,
+    center: bool
+) -> PyResult<Py<PyAny>> {
+    quantile_template(
+        py,
+        array,
+        length,
+        min_length,
+        0.5,
+        stats::Interpolation::Midpoint,
+        parallel,
+        axis,
+        center
+    )
+}
 
-    if parallel {
-        input_columns
-            .into_par_iter()
-            .zip(output_columns.par_iter_mut())
-            .for_each(|(input_col, output_col)| {
-                process_median_column(&input_col, output_col, length, min_length, num_rows);
+#[pyfunction]
+#[pyo3(
+    signature = (array, length, min_length, q, interpolation, parallel, axis = 0, center = false)
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn move_quantile<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    length: usize,
+    min_length: usize,
+    q: f64,
+    interpolation: &str,
+    parallel: bool,
+    axis: isize,
+    center: bool
+) -> PyResult<Py<PyAny>> {
+    validation::check_quantile(q)?;
+    let interpolation = validation::get_interpolation(interpolation)?;
+    quantile_template(py, array, length, min_length, q, interpolation, parallel, axis, center)
+}
+
+#[pyfunction]
+#[pyo3(
+    signature = (array, length, min_length, qs, interpolation, parallel, axis = 0, center = false)
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn move_quantiles<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    length: usize,
+    min_length: usize,
+    qs: Vec<f64>,
+    interpolation: &str,
+    parallel: bool,
+    axis: isize,
+    center: bool
+) -> PyResult<Py<PyAny>> {
+    validation::check_window(length, min_length)?;
+    validation::check_quantiles(&qs)?;
+    let interpolation = validation::get_interpolation(interpolation)?;
+    dispatch!(array, T => {
+        let array = array.as_array();
+        let axis = validation::get_axis(array.ndim(), axis)?;
+        let num_rows = array.len_of(axis);
+        let mut shape: Vec<usize> = array.shape().to_vec();
+        shape.push(qs.len());
+        let mut output = ArrayD::<T>::from_elem(IxDyn(&shape), T::NAN);
+        let input_columns: Vec<_> = array.lanes(axis).into_iter().collect();
+        let mut output_columns: Vec<_> = output.lanes_mut(axis).into_iter().collect();
+
+        if parallel {
+            input_columns
+                .into_par_iter()
+                .zip(output_columns.par_chunks_mut(qs.len()))
+                .for_each(|(input_col, output_cols)| {
+                    process_window_columns(
+                        &input_col,
+                        output_cols,
+                        length,
+                        num_rows,
+                        center,
+                        |input_col, output_cols, num_rows| {
+                            process_quantiles_column(
+                                input_col,
+                                output_cols,
+                                length,
+                                min_length,
+                                num_rows,
+                                &qs,
+                                interpolation
+                            );
+                        }
+                    );
+                });
+        } else {
+            py.allow_threads(move || {
+                let output_chunks = output_columns.chunks_mut(qs.len());
+                for (input_col, output_cols) in input_columns.iter().zip(output_chunks) {
+                    process_window_columns(
+                        input_col,
+                        output_cols,
+                        length,
+                        num_rows,
+                        center,
+                        |input_col, output_cols, num_rows| {
+                            process_quantiles_column(
+                                input_col,
+                                output_cols,
+                                length,
+                                min_length,
+                                num_rows,
+                                &qs,
+                                interpolation
+                            );
+                        }
+                    );
+                }
             });
-    } else {
-        py.allow_threads(move || {
-            for (input_col, output_col) in input_columns.iter().zip(output_columns.iter_mut()) {
-                process_median_column(&input_col, output_col, length, min_length, num_rows);
-            }
-        });
-    }
+        }
+
+        Ok(PyArrayDyn::from_owned_array(py, output).into_any().unbind())
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (y, x, length, min_length, parallel, axis = 0, center = false))]
+#[allow(clippy::too_many_arguments)]
+pub fn move_linreg<'py>(
+    py: Python<'py>,
+    y: FloatArray<'py>,
+    x: FloatArray<'py>,
+    length: usize,
+    min_length: usize,
+    parallel: bool,
+    axis: isize,
+    center: bool
+) -> PyResult<Py<PyAny>> {
+    validation::check_window(length, min_length)?;
+    dispatch_pair!(y, x, T => {
+        let y = y.as_array();
+        let x = x.as_array();
+        validation::check_same_shape(y.shape(), x.shape())?;
+        let axis = validation::get_axis(y.ndim(), axis)?;
+        let x_columns: Vec<_> = x.lanes(axis).into_iter().collect();
+        linreg_template::<T, T>(py, y, x_columns, axis, length, min_length, parallel, center)
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (array, length, min_length, parallel, axis = 0, center = false))]
+pub fn move_trend<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    length: usize,
+    min_length: usize,
+    parallel: bool,
+    axis: isize,
+    center: bool
+) -> PyResult<Py<PyAny>> {
+    validation::check_window(length, min_length)?;
+    dispatch!(array, T => {
+        let array = array.as_array();
+        let axis = validation::get_axis(array.ndim(), axis)?;
+        let num_rows = array.len_of(axis);
+        let index = Array1::from_iter((0..num_rows).map(|row| row as f64));
+        let num_cols = array.len() / num_rows.max(1);
+        let x_columns = vec![index.view(); num_cols];
+        linreg_template::<f64, T>(
+            py,
+            array,
+            x_columns,
+            axis,
+            length,
+            min_length,
+            parallel,
+            center
+        )
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (y, x, length, min_length, parallel, axis = 0, center = false))]
+#[allow(clippy::too_many_arguments)]
+pub fn move_ols<'py>(
+    py: Python<'py>,
+    y: FloatArray<'py>,
+    x: FloatArray<'py>,
+    length: usize,
+    min_length: usize,
+    parallel: bool,
+    axis: isize,
+    center: bool
+) -> PyResult<Py<PyAny>> {
+    validation::check_window(length, min_length)?;
+    dispatch_pair!(y, x, T => {
+        let y = y.as_array();
+        let x = x.as_array();
+        validation::check_regressors(y.shape(), x.shape())?;
+        let axis = validation::get_axis(y.ndim(), axis)?;
+        let order: [usize; 3] = if axis == Axis(0) { [0, 1, 2] } else { [1, 0, 2] };
+        let mut output = ArrayD::<T>::from_elem(x.raw_dim(), T::NAN);
+        let x = x.permuted_axes(IxDyn(&order));
+        let (num_rows, num_regressors) = (x.shape()[0], x.shape()[2]);
+        let offset: usize = center_offset(length, num_rows, center);
+        let input_columns: Vec<_> = y.lanes(axis).into_iter().collect();
+        let regressor_columns: Vec<_> = x
+            .axis_iter(Axis(1))
+            .map(|view| view.into_dimensionality::<Ix2>().unwrap())
+            .collect();
+        let mut output_view = output.view_mut().permuted_axes(IxDyn(&order));
+        let output_columns: Vec<_> = output_view
+            .axis_iter_mut(Axis(1))
+            .map(|view| view.into_dimensionality::<Ix2>().unwrap())
+            .collect();
+
+        if parallel {
+            input_columns
+                .into_par_iter()
+                .zip(regressor_columns.into_par_iter())
+                .zip(output_columns.into_par_iter())
+                .for_each(|((input_col, regressor_col), mut output_col)| {
+                    process_ols_column(
+                        &input_col,
+                        &regressor_col,
+                        &mut output_col,
+                        length,
+                        min_length,
+                        num_rows,
+                        num_regressors,
+                        offset
+                    );
+                });
+        } else {
+            py.allow_threads(move || {
+                for ((input_col, regressor_col), mut output_col) in input_columns
+                    .iter()
+                    .zip(regressor_columns.iter())
+                    .zip(output_columns.into_iter()) {
+                    process_ols_column(
+                        input_col,
+                        regressor_col,
+                        &mut output_col,
+                        length,
+                        min_length,
+                        num_rows,
+                        num_regressors,
+                        offset
+                    );
+                }
+            });
+        }
 
-    Ok(PyArray2::from_owned_array(py, output).into())
+        Ok(output.into_pyarray(py).into_any().unbind())
+    })
 }
 
 #[pyfunction]
+#[pyo3(signature = (array, min_length, parallel, axis = 0))]
+pub fn expanding_median<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    min_length: usize,
+    parallel: bool,
+    axis: isize
+) -> PyResult<Py<PyAny>> {
+    quantile_template(
+        py,
+        array,
+        EXPANDING_LENGTH,
+        min_length,
+        0.5,
+        stats::Interpolation::Midpoint,
+        parallel,
+        axis,
+        false
+    )
+}
+
+#[pyfunction]
+#[pyo3(signature = (win_type, length, std = None))]
+pub fn get_window<'py>(
+    py: Python<'py>,
+    win_type: &str,
+    length: usize,
+    std: Option<f64>
+) -> PyResult<Bound<'py, PyArray1<f64>>> {
+    validation::check_window(length, 1)?;
+    let window_type = validation::get_window_type(win_type, std)?;
+    Ok(stats::window_weights(window_type, length).into_pyarray(py))
+}
+
+#[pyfunction]
+#[pyo3(signature = (array, length, min_length, parallel, axis = 0, center = false))]
 pub fn move_rank<'py>(
     py: Python<'py>,
-    array: PyReadonlyArray2<'py, f64>,
+    array: FloatArray<'py>,
+    length: usize,
+    min_length: usize,
+    parallel: bool,
+    axis: isize,
+    center: bool
+) -> PyResult<Py<PyAny>> {
+    validation::check_window(length, min_length)?;
+    dispatch!(array, T => {
+        let array = array.as_array();
+        let axis = validation::get_axis(array.ndim(), axis)?;
+        let num_rows = array.len_of(axis);
+        let mut output = ArrayD::<T>::from_elem(array.raw_dim(), T::NAN);
+        let input_columns: Vec<_> = array.lanes(axis).into_iter().collect();
+        let mut output_columns: Vec<_> = output.lanes_mut(axis).into_iter().collect();
+
+        if parallel {
+            input_columns
+                .into_par_iter()
+                .zip(output_columns.par_iter_mut())
+                .for_each(|(input_col, output_col)| {
+                    process_window_column(
+                        &input_col,
+                        output_col,
+                        length,
+                        num_rows,
+                        center,
+                        |input_col, output_col, padded_rows| {
+                            process_rank_column(
+                                input_col,
+                                output_col,
+                                length,
+                                min_length,
+                                padded_rows,
+                                padded_rows - num_rows
+                            );
+                        }
+                    );
+                });
+        } else {
+            py.allow_threads(move || {
+                for (input_col, output_col) in input_columns.iter().zip(output_columns.iter_mut()) {
+                    process_window_column(
+                        input_col,
+                        output_col,
+                        length,
+                        num_rows,
+                        center,
+                        |input_col, output_col, padded_rows| {
+                            process_rank_column(
+                                input_col,
+                                output_col,
+                                length,
+                                min_length,
+                                padded_rows,
+                                padded_rows - num_rows
+                            );
+                        }
+                    );
+                }
+            });
+        }
+
+        Ok(PyArrayDyn::from_owned_array(py, output).into_any().unbind())
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (array, parallel, axis = 0))]
+pub fn agg_median<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    parallel: bool,
+    axis: isize
+) -> PyResult<Py<PyAny>> {
+    dispatch!(array, T => {
+        let array = array.as_array();
+        let axis = validation::get_axis(array.ndim(), axis)?;
+        let mut shape = array.raw_dim();
+        shape[axis.index()] = 1;
+        let mut output = ArrayD::<T>::from_elem(shape, T::NAN);
+        let input_columns: Vec<_> = array.lanes(axis).into_iter().collect();
+        let mut output_columns: Vec<_> = output.lanes_mut(axis).into_iter().collect();
+
+        if parallel {
+            input_columns
+                .into_par_iter()
+                .zip(output_columns.par_iter_mut())
+                .for_each(|(input_col, output_col)| {
+                    process_agg_median_column(&input_col, output_col);
+                });
+        } else {
+            py.allow_threads(move || {
+                for (input_col, output_col) in input_columns.iter().zip(output_columns.iter_mut()) {
+                    process_agg_median_column(input_col, output_col);
+                }
+            });
+        }
+
+        Ok(PyArrayDyn::from_owned_array(py, output).into_any().unbind())
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (array, parallel, axis = 0))]
+pub fn agg_rank<'py>(
+    py: Python<'py>,
+    array: FloatArray<'py>,
+    parallel: bool,
+    axis: isize
+) -> PyResult<Py<PyAny>> {
+    dispatch!(array, T => {
+        let array = array.as_array();
+        let axis = validation::get_axis(array.ndim(), axis)?;
+        let mut output = ArrayD::<T>::from_elem(array.raw_dim(), T::NAN);
+        let input_columns: Vec<_> = array.lanes(axis).into_iter().collect();
+        let mut output_columns: Vec<_> = output.lanes_mut(axis).into_iter().collect();
+
+        if parallel {
+            input_columns
+                .into_par_iter()
+                .zip(output_columns.par_iter_mut())
+                .for_each(|(input_col, output_col)| {
+                    process_agg_rank_column(&input_col, output_col);
+                });
+        } else {
+            py.allow_threads(move || {
+                for (input_col, output_col) in input_columns.iter().zip(output_columns.iter_mut()) {
+                    process_agg_rank_column(input_col, output_col);
+                }
+            });
+        }
+
+        Ok(PyArrayDyn::from_owned_array(py, output).into_any().unbind())
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+fn linreg_template<X: Float, T: Float>(
+    py: Python<'_>,
+    array: ArrayViewD<'_, T>,
+    x_columns: Vec<ArrayView1<'_, X>>,
+    axis: Axis,
     length: usize,
     min_length: usize,
-    parallel: bool
-) -> PyResult<Py<PyArray2<f64>>> {
-    let array = array.as_array();
-    let (num_rows, num_cols) = array.dim();
-    let mut output = Array2::<f64>::from_elem((num_rows, num_cols), f64::NAN);
-    let input_columns: Vec<_> = array.columns().into_iter().collect();
-    let mut output_columns: Vec<_> = output.columns_mut().into_iter().collect();
+    parallel: bool,
+    center: bool
+) -> PyResult<Py<PyAny>> {
+    let num_rows = array.len_of(axis);
+    let mut shape: Vec<usize> = array.shape().to_vec();
+    shape.push(LINREG_OUTPUTS);
+    let mut output = ArrayD::<T>::from_elem(IxDyn(&shape), T::NAN);
+    let input_columns: Vec<_> = array.lanes(axis).into_iter().collect();
+    let mut output_columns: Vec<_> = output.lanes_mut(axis).into_iter().collect();
 
     if parallel {
         input_columns
             .into_par_iter()
-            .zip(output_columns.par_iter_mut())
-            .for_each(|(input_col, output_col)| {
-                process_rank_column(&input_col, output_col, length, min_length, num_rows);
+            .zip(x_columns.into_par_iter())
+            .zip(output_columns.par_chunks_mut(LINREG_OUTPUTS))
+            .for_each(|((input_col, x_col), output_cols)| {
+                process_window_pair(
+                    &x_col,
+                    &input_col,
+                    output_cols,
+                    length,
+                    num_rows,
+                    center,
+                    |x_col, input_col, output_cols, padded_rows| {
+                        process_linreg_column(
+                            x_col,
+                            input_col,
+                            output_cols,
+                            length,
+                            min_length,
+                            padded_rows,
+                            padded_rows - num_rows
+                        );
+                    }
+                );
             });
     } else {
         py.allow_threads(move || {
-            for (input_col, output_col) in input_columns.iter().zip(output_columns.iter_mut()) {
-                process_rank_column(&input_col, output_col, length, min_length, num_rows);
+            for ((input_col, x_col), output_cols) in input_columns
+                .iter()
+                .zip(x_columns.iter())
+                .zip(output_columns.chunks_mut(LINREG_OUTPUTS)) {
+                process_window_pair(
+                    x_col,
+                    input_col,
+                    output_cols,
+                    length,
+                    num_rows,
+                    center,
+                    |x_col, input_col, output_cols, padded_rows| {
+                        process_linreg_column(
+                            x_col,
+                            input_col,
+                            output_cols,
+                            length,
+                            min_length,
+                            padded_rows,
+                            padded_rows - num_rows
+                        );
+                    }
+                );
             }
         });
     }
 
-    Ok(PyArray2::from_owned_array(py, output).into())
+    Ok(PyArrayDyn::from_owned_array(py, output).into_any().unbind())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn quantile_template(
+    py: Python<'_>,
+    array: FloatArray<'_>,
+    length: usize,
+    min_length: usize,
+    quantile: f64,
+    interpolation: stats::Interpolation,
+    parallel: bool,
+    axis: isize,
+    center: bool
+) -> PyResult<Py<PyAny>> {
+    validation::check_window(length, min_length)?;
+    dispatch!(array, T => {
+        let array = array.as_array();
+        let axis = validation::get_axis(array.ndim(), axis)?;
+        let num_rows = array.len_of(axis);
+        let mut output = ArrayD::<T>::from_elem(array.raw_dim(), T::NAN);
+        let input_columns: Vec<_> = array.lanes(axis).into_iter().collect();
+        let mut output_columns: Vec<_> = output.lanes_mut(axis).into_iter().collect();
+
+        if parallel {
+            input_columns
+                .into_par_iter()
+                .zip(output_columns.par_iter_mut())
+                .for_each(|(input_col, output_col)| {
+                    process_window_column(
+                        &input_col,
+                        output_col,
+                        length,
+                        num_rows,
+                        center,
+                        |input_col, output_col, num_rows| {
+                            process_quantile_column(
+                                input_col,
+                                output_col,
+                                length,
+                                min_length,
+                                num_rows,
+                                quantile,
+                                interpolation
+                            );
+                        }
+                    );
+                });
+        } else {
+            py.allow_threads(move || {
+                for (input_col, output_col) in input_columns.iter().zip(output_columns.iter_mut()) {
+                    process_window_column(
+                        input_col,
+                        output_col,
+                        length,
+                        num_rows,
+                        center,
+                        |input_col, output_col, num_rows| {
+                            process_quantile_column(
+                                input_col,
+                                output_col,
+                                length,
+                                min_length,
+                                num_rows,
+                                quantile,
+                                interpolation
+                            );
+                        }
+                    );
+                }
+            });
+        }
+
+        Ok(PyArrayDyn::from_owned_array(py, output).into_any().unbind())
+    })
 }
 
 pub fn move_template<Stat: calculators::StatCalculator>(
     py: Python<'_>,
-    array: PyReadonlyArray2<'_, f64>,
+    array: FloatArray<'_>,
     length: usize,
     min_length: usize,
-    parallel: bool
-) -> PyResult<Py<PyArray2<f64>>> {
-    let array = array.as_array();
-    let (num_rows, num_cols) = array.dim();
-    let input_columns: Vec<_> = array.columns().into_iter().collect();
-    let mut output = Array2::<f64>::from_elem((num_rows, num_cols), f64::NAN);
-    let mut output_columns: Vec<_> = output.columns_mut().into_iter().collect();
+    parallel: bool,
+    axis: isize,
+    center: bool
+) -> PyResult<Py<PyAny>> {
+    validation::check_window(length, min_length)?;
+    dispatch!(array, T => {
+        let array = array.as_array();
+        let axis = validation::get_axis(array.ndim(), axis)?;
+        let num_rows = array.len_of(axis);
+        let input_columns: Vec<_> = array.lanes(axis).into_iter().collect();
+        let mut output = ArrayD::<T>::from_elem(array.raw_dim(), T::NAN);
+        let mut output_columns: Vec<_> = output.lanes_mut(axis).into_iter().collect();
 
-    if parallel {
-        input_columns
-            .into_par_iter()
-            .zip(output_columns.par_iter_mut())
-            .for_each(|(input_col, output_col)| {
-                process_stat_column::<Stat>(&input_col, output_col, length, min_length, num_rows);
+        if parallel {
+            input_columns
+                .into_par_iter()
+                .zip(output_columns.par_iter_mut())
+                .for_each(|(input_col, output_col)| {
+                    process_window_column(
+                        &input_col,
+                        output_col,
+                        length,
+                        num_rows,
+                        center,
+                        |input_col, output_col, num_rows| {
+                            process_stat_column::<T, Stat>(
+                                input_col,
+                                output_col,
+                                length,
+                                min_length,
+                                num_rows
+                            );
+                        }
+                    );
+                });
+        } else {
+            py.allow_threads(move || {
+                for (input_col, output_col) in input_columns.iter().zip(output_columns.iter_mut()) {
+                    process_window_column(
+                        input_col,
+                        output_col,
+                        length,
+                        num_rows,
+                        center,
+                        |input_col, output_col, num_rows| {
+                            process_stat_column::<T, Stat>(
+                                input_col,
+                                output_col,
+                                length,
+                                min_length,
+                                num_rows
+                            );
+                        }
+                    );
+                }
             });
-    } else {
-        py.allow_threads(move || {
-            for (input_col, output_col) in input_columns.iter().zip(output_columns.iter_mut()) {
-                process_stat_column::<Stat>(&input_col, output_col, length, min_length, num_rows);
-            }
-        });
-    }
+        }
 
-    Ok(output.into_pyarray(py).into())
+        Ok(output.into_pyarray(py).into_any().unbind())
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn move_pair_template<Stat: calculators::PairStatCalculator>(
+    py: Python<'_>,
+    left: FloatArray<'_>,
+    right: FloatArray<'_>,
+    length: usize,
+    min_length: usize,
+    parallel: bool,
+    axis: isize,
+    center: bool
+) -> PyResult<Py<PyAny>> {
+    validation::check_window(length, min_length)?;
+    dispatch_pair!(left, right, T => {
+        let left = left.as_array();
+        let right = right.as_array();
+        validation::check_same_shape(left.shape(), right.shape())?;
+        let axis = validation::get_axis(left.ndim(), axis)?;
+        let num_rows = left.len_of(axis);
+        let left_columns: Vec<_> = left.lanes(axis).into_iter().collect();
+        let right_columns: Vec<_> = right.lanes(axis).into_iter().collect();
+        let mut output = ArrayD::<T>::from_elem(left.raw_dim(), T::NAN);
+        let mut output_columns: Vec<_> = output.lanes_mut(axis).into_iter().collect();
+
+        if parallel {
+            left_columns
+                .into_par_iter()
+                .zip(right_columns.into_par_iter())
+                .zip(output_columns.par_iter_mut())
+                .for_each(|((left_col, right_col), output_col)| {
+                    process_window_pair(
+                        &left_col,
+                        &right_col,
+                        std::slice::from_mut(output_col),
+                        length,
+                        num_rows,
+                        center,
+                        |left_col, right_col, output_cols, num_rows| {
+                            process_pair_column::<T, Stat>(
+                                left_col,
+                                right_col,
+                                &mut output_cols[0],
+                                length,
+                                min_length,
+                                num_rows
+                            );
+                        }
+                    );
+                });
+        } else {
+            py.allow_threads(move || {
+                for ((left_col, right_col), output_col) in left_columns
+                    .iter()
+                    .zip(right_columns.iter())
+                    .zip(output_columns.iter_mut()) {
+                    process_window_pair(
+                        left_col,
+                        right_col,
+                        std::slice::from_mut(output_col),
+                        length,
+                        num_rows,
+                        center,
+                        |left_col, right_col, output_cols, num_rows| {
+                            process_pair_column::<T, Stat>(
+                                left_col,
+                                right_col,
+                                &mut output_cols[0],
+                                length,
+                                min_length,
+                                num_rows
+                            );
+                        }
+                    );
+                }
+            });
+        }
+
+        Ok(output.into_pyarray(py).into_any().unbind())
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn move_with_template<Stat: calculators::PairStatCalculator>(
+    py: Python<'_>,
+    array: FloatArray<'_>,
+    target: FloatArray<'_>,
+    length: usize,
+    min_length: usize,
+    parallel: bool,
+    axis: isize,
+    center: bool
+) -> PyResult<Py<PyAny>> {
+    validation::check_window(length, min_length)?;
+    dispatch_pair!(array, target, T => {
+        let array = array.as_array();
+        let axis = validation::get_axis(array.ndim(), axis)?;
+        let num_rows = array.len_of(axis);
+        let target = target.as_array();
+        validation::check_target(target.shape(), num_rows)?;
+        let target_col = target.into_dimensionality::<Ix1>().unwrap();
+        let input_columns: Vec<_> = array.lanes(axis).into_iter().collect();
+        let mut output = ArrayD::<T>::from_elem(array.raw_dim(), T::NAN);
+        let mut output_columns: Vec<_> = output.lanes_mut(axis).into_iter().collect();
+
+        if parallel {
+            input_columns
+                .into_par_iter()
+                .zip(output_columns.par_iter_mut())
+                .for_each(|(input_col, output_col)| {
+                    process_window_pair(
+                        &input_col,
+                        &target_col,
+                        std::slice::from_mut(output_col),
+                        length,
+                        num_rows,
+                        center,
+                        |left_col, right_col, output_cols, num_rows| {
+                            process_pair_column::<T, Stat>(
+                                left_col,
+                                right_col,
+                                &mut output_cols[0],
+                                length,
+                                min_length,
+                                num_rows
+                            );
+                        }
+                    );
+                });
+        } else {
+            py.allow_threads(move || {
+                for (input_col, output_col) in input_columns.iter().zip(output_columns.iter_mut()) {
+                    process_window_pair(
+                        input_col,
+                        &target_col,
+                        std::slice::from_mut(output_col),
+                        length,
+                        num_rows,
+                        center,
+                        |left_col, right_col, output_cols, num_rows| {
+                            process_pair_column::<T, Stat>(
+                                left_col,
+                                right_col,
+                                &mut output_cols[0],
+                                length,
+                                min_length,
+                                num_rows
+                            );
+                        }
+                    );
+                }
+            });
+        }
+
+        Ok(output.into_pyarray(py).into_any().unbind())
+    })
+}
+
+pub fn move_matrix_template<Stat: calculators::PairStatCalculator>(
+    py: Python<'_>,
+    array: FloatArray<'_>,
+    length: usize,
+    min_length: usize,
+    parallel: bool,
+    axis: isize,
+    center: bool
+) -> PyResult<Py<PyAny>> {
+    validation::check_window(length, min_length)?;
+    dispatch!(array, T => {
+        let array = array.as_array();
+        validation::check_matrix(array.ndim())?;
+        let axis = validation::get_axis(array.ndim(), axis)?;
+        let num_rows = array.len_of(axis);
+        let input_columns: Vec<_> = array.lanes(axis).into_iter().collect();
+        let num_cols = input_columns.len();
+        let mut output = Array3::<T>::from_elem((num_rows, num_cols, num_cols), T::NAN);
+        let mut output_pairs: Vec<_> = output
+            .lanes_mut(Axis(0))
+            .into_iter()
+            .enumerate()
+            .map(|(idx, output_col)| (idx / num_cols, idx % num_cols, output_col))
+            .filter(|(left, right, _)| left <= right)
+            .collect();
+
+        if parallel {
+            output_pairs.par_iter_mut().for_each(|(left, right, output_col)| {
+                process_window_pair(
+                    &input_columns[*left],
+                    &input_columns[*right],
+                    std::slice::from_mut(output_col),
+                    length,
+                    num_rows,
+                    center,
+                    |left_col, right_col, output_cols, num_rows| {
+                        process_pair_column::<T, Stat>(
+                            left_col,
+                            right_col,
+                            &mut output_cols[0],
+                            length,
+                            min_length,
+                            num_rows
+                        );
+                    }
+                );
+            });
+        } else {
+            py.allow_threads(|| {
+                for (left, right, output_col) in output_pairs.iter_mut() {
+                    process_window_pair(
+                        &input_columns[*left],
+                        &input_columns[*right],
+                        std::slice::from_mut(output_col),
+                        length,
+                        num_rows,
+                        center,
+                        |left_col, right_col, output_cols, num_rows| {
+                            process_pair_column::<T, Stat>(
+                                left_col,
+                                right_col,
+                                &mut output_cols[0],
+                                length,
+                                min_length,
+                                num_rows
+                            );
+                        }
+                    );
+                }
+            });
+        }
+
+        mirror_matrix(&mut output);
+        Ok(output.into_pyarray(py).into_any().unbind())
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn ewm_template<Stat: calculators::EwmStatCalculator>(
+    py: Python<'_>,
+    array: FloatArray<'_>,
+    min_length: usize,
+    alpha: f64,
+    adjust: bool,
+    ignore_na: bool,
+    parallel: bool,
+    axis: isize
+) -> PyResult<Py<PyAny>> {
+    validation::check_min_length(min_length)?;
+    dispatch!(array, T => {
+        let array = array.as_array();
+        let axis = validation::get_axis(array.ndim(), axis)?;
+        let input_columns: Vec<_> = array.lanes(axis).into_iter().collect();
+        let mut output = ArrayD::<T>::from_elem(array.raw_dim(), T::NAN);
+        let mut output_columns: Vec<_> = output.lanes_mut(axis).into_iter().collect();
+
+        if parallel {
+            input_columns
+                .into_par_iter()
+                .zip(output_columns.par_iter_mut())
+                .for_each(|(input_col, output_col)| {
+                    process_ewm_column::<T, Stat>(
+                        &input_col,
+                        &input_col,
+                        output_col,
+                        min_length,
+                        alpha,
+                        adjust,
+                        ignore_na
+                    );
+                });
+        } else {
+            py.allow_threads(move || {
+                for (input_col, output_col) in input_columns.iter().zip(output_columns.iter_mut()) {
+                    process_ewm_column::<T, Stat>(
+                        input_col,
+                        input_col,
+                        output_col,
+                        min_length,
+                        alpha,
+                        adjust,
+                        ignore_na
+                    );
+                }
+            });
+        }
+
+        Ok(output.into_pyarray(py).into_any().unbind())
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn ewm_pair_template<Stat: calculators::EwmStatCalculator>(
+    py: Python<'_>,
+    left: FloatArray<'_>,
+    right: FloatArray<'_>,
+    min_length: usize,
+    alpha: f64,
+    adjust: bool,
+    ignore_na: bool,
+    parallel: bool,
+    axis: isize
+) -> PyResult<Py<PyAny>> {
+    validation::check_min_length(min_length)?;
+    dispatch_pair!(left, right, T => {
+        let left = left.as_array();
+        let right = right.as_array();
+        validation::check_same_shape(left.shape(), right.shape())?;
+        let axis = validation::get_axis(left.ndim(), axis)?;
+        let left_columns: Vec<_> = left.lanes(axis).into_iter().collect();
+        let right_columns: Vec<_> = right.lanes(axis).into_iter().collect();
+        let mut output = ArrayD::<T>::from_elem(left.raw_dim(), T::NAN);
+        let mut output_columns: Vec<_> = output.lanes_mut(axis).into_iter().collect();
+
+        if parallel {
+            left_columns
+                .into_par_iter()
+                .zip(right_columns.into_par_iter())
+                .zip(output_columns.par_iter_mut())
+                .for_each(|((left_col, right_col), output_col)| {
+                    process_ewm_column::<T, Stat>(
+                        &left_col,
+                        &right_col,
+                        output_col,
+                        min_length,
+                        alpha,
+                        adjust,
+                        ignore_na
+                    );
+                });
+        } else {
+            py.allow_threads(move || {
+                for ((left_col, right_col), output_col) in left_columns
+                    .iter()
+                    .zip(right_columns.iter())
+                    .zip(output_columns.iter_mut()) {
+                    process_ewm_column::<T, Stat>(
+                        left_col,
+                        right_col,
+                        output_col,
+                        min_length,
+                        alpha,
+                        adjust,
+                        ignore_na
+                    );
+                }
+            });
+        }
+
+        Ok(output.into_pyarray(py).into_any().unbind())
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn ewm_matrix_template<Stat: calculators::EwmStatCalculator>(
+    py: Python<'_>,
+    array: FloatArray<'_>,
+    min_length: usize,
+    alpha: f64,
+    adjust: bool,
+    ignore_na: bool,
+    parallel: bool,
+    axis: isize
+) -> PyResult<Py<PyAny>> {
+    validation::check_min_length(min_length)?;
+    dispatch!(array, T => {
+        let array = array.as_array();
+        validation::check_matrix(array.ndim())?;
+        let axis = validation::get_axis(array.ndim(), axis)?;
+        let num_rows = array.len_of(axis);
+        let input_columns: Vec<_> = array.lanes(axis).into_iter().collect();
+        let num_cols = input_columns.len();
+        let mut output = Array3::<T>::from_elem((num_rows, num_cols, num_cols), T::NAN);
+        let mut output_pairs: Vec<_> = output
+            .lanes_mut(Axis(0))
+            .into_iter()
+            .enumerate()
+            .map(|(idx, output_col)| (idx / num_cols, idx % num_cols, output_col))
+            .filter(|(left, right, _)| left <= right)
+            .collect();
+
+        if parallel {
+            output_pairs.par_iter_mut().for_each(|(left, right, output_col)| {
+                process_ewm_column::<T, Stat>(
+                    &input_columns[*left],
+                    &input_columns[*right],
+                    output_col,
+                    min_length,
+                    alpha,
+                    adjust,
+                    ignore_na
+                );
+            });
+        } else {
+            py.allow_threads(|| {
+                for (left, right, output_col) in output_pairs.iter_mut() {
+                    process_ewm_column::<T, Stat>(
+                        &input_columns[*left],
+                        &input_columns[*right],
+                        output_col,
+                        min_length,
+                        alpha,
+                        adjust,
+                        ignore_na
+                    );
+                }
+            });
+        }
+        mirror_matrix(&mut output);
+
+        Ok(output.into_pyarray(py).into_any().unbind())
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn move_weighted_template(
+    py: Python<'_>,
+    array: FloatArray<'_>,
+    weights: Weights,
+    min_length: usize,
+    normalize: bool,
+    parallel: bool,
+    axis: isize,
+    center: bool,
+    length: Option<usize>,
+    std: Option<f64>
+) -> PyResult<Py<PyAny>> {
+    let weights: Vec<f64> = match weights {
+        Weights::Named(win_type) => validation::get_named_weights(&win_type, length, std)?,
+        Weights::Values(weights) => {
+            validation::check_explicit_weights(length, std)?;
+            weights
+        }
+    };
+    validation::check_weights(&weights)?;
+    validation::check_window(weights.len(), min_length)?;
+    dispatch!(array, T => {
+        let array = array.as_array();
+        let axis = validation::get_axis(array.ndim(), axis)?;
+        let num_rows = array.len_of(axis);
+        let input_columns: Vec<_> = array.lanes(axis).into_iter().collect();
+        let mut output = ArrayD::<T>::from_elem(array.raw_dim(), T::NAN);
+        let mut output_columns: Vec<_> = output.lanes_mut(axis).into_iter().collect();
+
+        if parallel {
+            input_columns
+                .into_par_iter()
+                .zip(output_columns.par_iter_mut())
+                .for_each(|(input_col, output_col)| {
+                    process_window_column(
+                        &input_col,
+                        output_col,
+                        weights.len(),
+                        num_rows,
+                        center,
+                        |input_col, output_col, num_rows| {
+                            process_weighted_column(
+                                input_col,
+                                output_col,
+                                &weights,
+                                min_length,
+                                num_rows,
+                                normalize
+                            );
+                        }
+                    );
+                });
+        } else {
+            py.allow_threads(move || {
+                for (input_col, output_col) in input_columns.iter().zip(output_columns.iter_mut()) {
+                    process_window_column(
+                        input_col,
+                        output_col,
+                        weights.len(),
+                        num_rows,
+                        center,
+                        |input_col, output_col, num_rows| {
+                            process_weighted_column(
+                                input_col,
+                                output_col,
+                                &weights,
+                                min_length,
+                                num_rows,
+                                normalize
+                            );
+                        }
+                    );
+                }
+            });
+        }
+
+        Ok(output.into_pyarray(py).into_any().unbind())
+    })
 }
 
 pub fn move_deque_template<Stat: calculators::DequeStatCalculator>(
     py: Python<'_>,
-    array: PyReadonlyArray2<'_, f64>,
+    array: FloatArray<'_>,
     length: usize,
     min_length: usize,
-    parallel: bool
-) -> PyResult<Py<PyArray2<f64>>> {
-    let array = array.as_array();
-    let (num_rows, num_cols) = array.dim();
-    let input_columns: Vec<_> = array.columns().into_iter().collect();
-    let mut output = Array2::<f64>::from_elem((num_rows, num_cols), f64::NAN);
-    let mut output_columns: Vec<_> = output.columns_mut().into_iter().collect();
+    parallel: bool,
+    axis: isize,
+    center: bool
+) -> PyResult<Py<PyAny>> {
+    validation::check_window(length, min_length)?;
+    dispatch!(array, T => {
+        let array = array.as_array();
+        let axis = validation::get_axis(array.ndim(), axis)?;
+        let num_rows = array.len_of(axis);
+        let input_columns: Vec<_> = array.lanes(axis).into_iter().collect();
+        let mut output = ArrayD::<T>::from_elem(array.raw_dim(), T::NAN);
+        let mut output_columns: Vec<_> = output.lanes_mut(axis).into_iter().collect();
 
-    if parallel {
-        input_columns
-            .into_par_iter()
-            .zip(output_columns.par_iter_mut())
-            .for_each(|(input_col, output_col)| {
-                process_deque_column::<Stat>(&input_col, output_col, length, min_length, num_rows);
+        if parallel {
+            input_columns
+                .into_par_iter()
+                .zip(output_columns.par_iter_mut())
+                .for_each(|(input_col, output_col)| {
+                    process_window_column(
+                        &input_col,
+                        output_col,
+                        length,
+                        num_rows,
+                        center,
+                        |input_col, output_col, num_rows| {
+                            process_deque_column::<T, Stat>(
+                                input_col,
+                                output_col,
+                                length,
+                                min_length,
+                                num_rows,
+                                false
+                            );
+                        }
+                    );
+                });
+        } else {
+            py.allow_threads(move || {
+                for (input_col, output_col) in input_columns.iter().zip(output_columns.iter_mut()) {
+                    process_window_column(
+                        input_col,
+                        output_col,
+                        length,
+                        num_rows,
+                        center,
+                        |input_col, output_col, num_rows| {
+                            process_deque_column::<T, Stat>(
+                                input_col,
+                                output_col,
+                                length,
+                                min_length,
+                                num_rows,
+                                false
+                            );
+                        }
+                    );
+                }
             });
-    } else {
-        py.allow_threads(move || {
-            for (input_col, output_col) in input_columns.iter().zip(output_columns.iter_mut()) {
-                process_deque_column::<Stat>(&input_col, output_col, length, min_length, num_rows);
-            }
-        });
+        }
+
+        Ok(output.into_pyarray(py).into_any().unbind())
+    })
+}
+
+pub fn move_arg_template<Stat: calculators::DequeStatCalculator>(
+    py: Python<'_>,
+    array: FloatArray<'_>,
+    length: usize,
+    min_length: usize,
+    parallel: bool,
+    axis: isize,
+    center: bool
+) -> PyResult<Py<PyAny>> {
+    validation::check_window(length, min_length)?;
+    dispatch!(array, T => {
+        let array = array.as_array();
+        let axis = validation::get_axis(array.ndim(), axis)?;
+        let num_rows = array.len_of(axis);
+        let input_columns: Vec<_> = array.lanes(axis).into_iter().collect();
+        let mut output = ArrayD::<T>::from_elem(array.raw_dim(), T::NAN);
+        let mut output_columns: Vec<_> = output.lanes_mut(axis).into_iter().collect();
+
+        if parallel {
+            input_columns
+                .into_par_iter()
+                .zip(output_columns.par_iter_mut())
+                .for_each(|(input_col, output_col)| {
+                    process_window_column(
+                        &input_col,
+                        output_col,
+                        length,
+                        num_rows,
+                        center,
+                        |input_col, output_col, num_rows| {
+                            process_deque_column::<T, Stat>(
+                                input_col,
+                                output_col,
+                                length,
+                                min_length,
+                                num_rows,
+                                true
+                            );
+                        }
+                    );
+                });
+        } else {
+            py.allow_threads(move || {
+                for (input_col, output_col) in input_columns.iter().zip(output_columns.iter_mut()) {
+                    process_window_column(
+                        input_col,
+                        output_col,
+                        length,
+                        num_rows,
+                        center,
+                        |input_col, output_col, num_rows| {
+                            process_deque_column::<T, Stat>(
+                                input_col,
+                                output_col,
+                                length,
+                                min_length,
+                                num_rows,
+                                true
+                            );
+                        }
+                    );
+                }
+            });
+        }
+
+        Ok(output.into_pyarray(py).into_any().unbind())
+    })
+}
+
+pub fn agg_template<Stat: calculators::StatCalculator>(
+    py: Python<'_>,
+    array: FloatArray<'_>,
+    parallel: bool,
+    axis: isize
+) -> PyResult<Py<PyAny>> {
+    dispatch!(array, T => {
+        let array = array.as_array();
+        let axis = validation::get_axis(array.ndim(), axis)?;
+        let mut shape = array.raw_dim();
+        shape[axis.index()] = 1;
+        let input_columns: Vec<_> = array.lanes(axis).into_iter().collect();
+        let mut output = ArrayD::<T>::from_elem(shape, T::NAN);
+        let mut output_columns: Vec<_> = output.lanes_mut(axis).into_iter().collect();
+
+        if parallel {
+            input_columns
+                .into_par_iter()
+                .zip(output_columns.par_iter_mut())
+                .for_each(|(input_col, output_col)| {
+                    process_agg_column::<T, Stat>(&input_col, output_col);
+                });
+        } else {
+            py.allow_threads(move || {
+                for (input_col, output_col) in input_columns.iter().zip(output_columns.iter_mut()) {
+                    process_agg_column::<T, Stat>(input_col, output_col);
+                }
+            });
+        }
+
+        Ok(output.into_pyarray(py).into_any().unbind())
+    })
+}
+
+pub fn agg_deque_template<Stat: calculators::DequeStatCalculator>(
+    py: Python<'_>,
+    array: FloatArray<'_>,
+    parallel: bool,
+    axis: isize
+) -> PyResult<Py<PyAny>> {
+    dispatch!(array, T => {
+        let array = array.as_array();
+        let axis = validation::get_axis(array.ndim(), axis)?;
+        let mut shape = array.raw_dim();
+        shape[axis.index()] = 1;
+        let input_columns: Vec<_> = array.lanes(axis).into_iter().collect();
+        let mut output = ArrayD::<T>::from_elem(shape, T::NAN);
+        let mut output_columns: Vec<_> = output.lanes_mut(axis).into_iter().collect();
+
+        if parallel {
+            input_columns
+                .into_par_iter()
+                .zip(output_columns.par_iter_mut())
+                .for_each(|(input_col, output_col)| {
+                    if let Some((val, _)) = process_agg_deque_column::<T, Stat>(&input_col) {
+                        output_col[0] = val;
+                    }
+                });
+        } else {
+            py.allow_threads(move || {
+                for (input_col, output_col) in input_columns.iter().zip(output_columns.iter_mut()) {
+                    if let Some((val, _)) = process_agg_deque_column::<T, Stat>(input_col) {
+                        output_col[0] = val;
+                    }
+                }
+            });
+        }
+
+        Ok(output.into_pyarray(py).into_any().unbind())
+    })
+}
+
+pub fn agg_arg_template<Stat: calculators::DequeStatCalculator>(
+    py: Python<'_>,
+    array: FloatArray<'_>,
+    parallel: bool,
+    axis: isize
+) -> PyResult<Py<PyArrayDyn<i64>>> {
+    dispatch!(array, T => {
+        let array = array.as_array();
+        let axis = validation::get_axis(array.ndim(), axis)?;
+        let mut shape = array.raw_dim();
+        shape[axis.index()] = 1;
+        let input_columns: Vec<_> = array.lanes(axis).into_iter().collect();
+        let mut output = ArrayD::<i64>::from_elem(shape, -1);
+        let mut output_columns: Vec<_> = output.lanes_mut(axis).into_iter().collect();
+
+        if parallel {
+            input_columns
+                .into_par_iter()
+                .zip(output_columns.par_iter_mut())
+                .for_each(|(input_col, output_col)| {
+                    if let Some((_, idx)) = process_agg_deque_column::<T, Stat>(&input_col) {
+                        output_col[0] = idx as i64;
+                    }
+                });
+        } else {
+            py.allow_threads(move || {
+                for (input_col, output_col) in input_columns.iter().zip(output_columns.iter_mut()) {
+                    if let Some((_, idx)) = process_agg_deque_column::<T, Stat>(input_col) {
+                        output_col[0] = idx as i64;
+                    }
+                }
+            });
+        }
+
+        Ok(output.into_pyarray(py).into())
+    })
+}
+
+fn process_window_column<T: Float>(
+    input_col: &ArrayView1<T>,
+    output_col: &mut ArrayViewMut1<T>,
+    length: usize,
+    num_rows: usize,
+    center: bool,
+    process: impl Fn(&ArrayView1<T>, &mut ArrayViewMut1<T>, usize)
+) {
+    process_window_columns(
+        input_col,
+        std::slice::from_mut(output_col),
+        length,
+        num_rows,
+        center,
+        |input_col, output_cols, num_rows| process(input_col, &mut output_cols[0], num_rows)
+    );
+}
+
+fn process_window_columns<T: Float>(
+    input_col: &ArrayView1<T>,
+    output_cols: &mut [ArrayViewMut1<T>],
+    length: usize,
+    num_rows: usize,
+    center: bool,
+    process: impl Fn(&ArrayView1<T>, &mut [ArrayViewMut1<T>], usize)
+) {
+    let offset: usize = center_offset(length, num_rows, center);
+    let input_col = pad_column(input_col, offset);
+    process_centered(output_cols, offset, |output_cols| {
+        process(&input_col.view(), output_cols, num_rows + offset);
+    });
+}
+
+fn process_window_pair<X: Float, T: Float>(
+    left_col: &ArrayView1<X>,
+    right_col: &ArrayView1<T>,
+    output_cols: &mut [ArrayViewMut1<T>],
+    length: usize,
+    num_rows: usize,
+    center: bool,
+    process: impl Fn(&ArrayView1<X>, &ArrayView1<T>, &mut [ArrayViewMut1<T>], usize)
+) {
+    let offset: usize = center_offset(length, num_rows, center);
+    let left_col = pad_column(left_col, offset);
+    let right_col = pad_column(right_col, offset);
+    process_centered(output_cols, offset, |output_cols| {
+        process(&left_col.view(), &right_col.view(), output_cols, num_rows + offset);
+    });
+}
+
+fn center_offset(length: usize, num_rows: usize, center: bool) -> usize {
+    if center { ((length - 1) / 2).min(num_rows) } else { 0 }
+}
+
+fn pad_column<'a, T: Float>(input_col: &ArrayView1<'a, T>, offset: usize) -> CowArray<'a, T, Ix1> {
+    if offset == 0 {
+        return CowArray::from(*input_col);
     }
+    let num_rows: usize = input_col.len();
+    let mut padded_col = Array1::<T>::from_elem(num_rows + offset, T::NAN);
+    padded_col.slice_mut(s![..num_rows]).assign(input_col);
+    CowArray::from(padded_col)
+}
 
-    Ok(output.into_pyarray(py).into())
+fn process_centered<T: Float>(
+    output_cols: &mut [ArrayViewMut1<T>],
+    offset: usize,
+    process: impl FnOnce(&mut [ArrayViewMut1<T>])
+) {
+    if offset == 0 {
+        process(output_cols);
+        return;
+    }
+    let mut padded_cols: Vec<Array1<T>> = output_cols
+        .iter()
+        .map(|output_col| Array1::<T>::from_elem(output_col.len() + offset, T::NAN))
+        .collect();
+    let mut padded_views: Vec<_> = padded_cols.iter_mut().map(|col| col.view_mut()).collect();
+    process(&mut padded_views);
+    for (output_col, padded_col) in output_cols.iter_mut().zip(&padded_cols) {
+        output_col.assign(&padded_col.slice(s![offset..]));
+    }
 }
 
-fn process_stat_column<Stat: calculators::StatCalculator>(
-    input_col: &ArrayView1<f64>,
-    output_col: &mut ArrayViewMut1<f64>,
+fn process_stat_column<T: Float, Stat: calculators::StatCalculator>(
+    input_col: &ArrayView1<T>,
+    output_col: &mut ArrayViewMut1<T>,
     length: usize,
     min_length: usize,
     num_rows: usize
 ) {
     let mut state = Stat::new();
+    Stat::set_length(&mut state, length);
     let mut window = calculators::WindowState::new();
 
-    for row in 0..length {
+    for row in 0..length.min(num_rows) {
         window.current = input_col[row];
+        Stat::advance(&mut state);
         if !window.current.is_nan() {
             window.observations += 1;
             Stat::add_value(&mut state, window.current);
@@ -154,200 +1569,1056 @@ fn process_stat_column<Stat: calculators::StatCalculator>(
     }
 
     for row in length..num_rows {
-        window.refresh(&input_col, row, length);
+        window.refresh(input_col, row, length);
         window.compute_row::<Stat>(&mut state);
+        if Stat::is_stale(&state) {
+            state = Stat::new();
+            Stat::set_length(&mut state, length);
+            for &value in input_col.slice(s![row + 1 - length..=row]) {
+                Stat::advance(&mut state);
+                if !value.is_nan() {
+                    Stat::add_value(&mut state, value);
+                }
+            }
+        }
         if window.observations >= min_length {
             output_col[row] = Stat::get(&state, window.observations);
         }
     }
 }
 
-fn process_deque_column<Stat: calculators::DequeStatCalculator>(
-    input_col: &ArrayView1<f64>,
-    output_col: &mut ArrayViewMut1<f64>,
+fn process_pair_column<T: Float, Stat: calculators::PairStatCalculator>(
+    left_col: &ArrayView1<T>,
+    right_col: &ArrayView1<T>,
+    output_col: &mut ArrayViewMut1<T>,
     length: usize,
     min_length: usize,
     num_rows: usize
+) {
+    roll_pair_column(left_col, right_col, length, num_rows, |row, observations, state| {
+        if observations >= min_length {
+            output_col[row] = Stat::get(state);
+        }
+    });
+}
+
+fn process_linreg_column<X: Float, T: Float>(
+    x_col: &ArrayView1<X>,
+    y_col: &ArrayView1<T>,
+    output_cols: &mut [ArrayViewMut1<T>],
+    length: usize,
+    min_length: usize,
+    num_rows: usize,
+    offset: usize
+) {
+    roll_pair_column(x_col, y_col, length, num_rows, |row, observations, state| {
+        if row >= offset && observations >= min_length {
+            let (slope, intercept, rsquared) = state.regression();
+            let (x, y) = (x_col[row - offset].to_f64(), y_col[row - offset].to_f64());
+            let residual: f64 = state.residual(x, y);
+            for (output_col, value) in output_cols
+                .iter_mut()
+                .zip([slope, intercept, rsquared, residual]) {
+                output_col[row] = T::from_f64(value);
+            }
+        }
+    });
+}
+
+#[allow(clippy::too_many_arguments)]
+fn process_ols_column<T: Float>(
+    input_col: &ArrayView1<T>,
+    regressor_col: &ArrayView2<T>,
+    output_col: &mut ArrayViewMut2<T>,
+    length: usize,
+    min_length: usize,
+    num_rows: usize,
+    num_regressors: usize,
+    offset: usize
+) {
+    let mut state = calculators::Ols::new(num_regressors);
+    let mut regressors: Vec<f64> = vec![0.0; num_regressors];
+    let mut observations: usize = 0;
+
+    let load_row = |row: usize, regressors: &mut [f64]| -> Option<f64> {
+        if row >= num_rows {
+            return None;
+        }
+        let target: T = input_col[row];
+        if target.is_nan() {
+            return None;
+        }
+        for (regressor, &value) in regressors.iter_mut().zip(regressor_col.row(row)) {
+            if value.is_nan() {
+                return None;
+            }
+            *regressor = value.to_f64();
+        }
+        Some(target.to_f64())
+    };
+
+    for row in 0..num_rows + offset {
+        if let Some(target) = load_row(row, &mut regressors) {
+            observations += 1;
+            state.add(&regressors, target);
+        }
+
+        if row >= length {
+            if let Some(target) = load_row(row - length, &mut regressors) {
+                observations -= 1;
+                state.remove(&regressors, target);
+            }
+            if state.is_stale() {
+                state.reset();
+                for idx in row + 1 - length..=row {
+                    if let Some(target) = load_row(idx, &mut regressors) {
+                        state.add(&regressors, target);
+                    }
+                }
+            }
+        }
+
+        if row >= offset && observations >= min_length && state.solve() {
+            let coefficients = state.coefficients.iter();
+            let output_row = output_col.row_mut(row - offset);
+            for (output, &coefficient) in output_row.into_iter().zip(coefficients) {
+                *output = T::from_f64(coefficient);
+            }
+        }
+    }
+}
+
+fn roll_pair_column<X: Float, Y: Float>(
+    x_col: &ArrayView1<X>,
+    y_col: &ArrayView1<Y>,
+    length: usize,
+    num_rows: usize,
+    mut on_row: impl FnMut(usize, usize, &calculators::CoMoments)
+) {
+    let mut state = calculators::CoMoments::new();
+    let mut observations: usize = 0;
+
+    for row in 0..length.min(num_rows) {
+        let (current_x, current_y) = (x_col[row], y_col[row]);
+        if !current_x.is_nan() && !current_y.is_nan() {
+            observations += 1;
+            state.add(current_x.to_f64(), current_y.to_f64());
+        }
+        on_row(row, observations, &state);
+    }
+
+    for row in length..num_rows {
+        let (current_x, current_y) = (x_col[row], y_col[row]);
+        if !current_x.is_nan() && !current_y.is_nan() {
+            observations += 1;
+            state.add(current_x.to_f64(), current_y.to_f64());
+        }
+
+        let (precedent_x, precedent_y) = (x_col[row - length], y_col[row - length]);
+        if !precedent_x.is_nan() && !precedent_y.is_nan() {
+            observations -= 1;
+            state.remove(precedent_x.to_f64(), precedent_y.to_f64());
+        }
+
+        if state.is_stale() {
+            state = calculators::CoMoments::new();
+            for idx in row + 1 - length..=row {
+                let (x, y) = (x_col[idx], y_col[idx]);
+                if !x.is_nan() && !y.is_nan() {
+                    state.add(x.to_f64(), y.to_f64());
+                }
+            }
+        }
+        on_row(row, observations, &state);
+    }
+}
+
+fn process_ewm_column<T: Float, Stat: calculators::EwmStatCalculator>(
+    left_col: &ArrayView1<T>,
+    right_col: &ArrayView1<T>,
+    output_col: &mut ArrayViewMut1<T>,
+    min_length: usize,
+    alpha: f64,
+    adjust: bool,
+    ignore_na: bool
+) {
+    let mut state = calculators::Ewm::new(alpha, adjust, ignore_na);
+    let mut observations: usize = 0;
+
+    for (row, (&x, &y)) in left_col.iter().zip(right_col.iter()).enumerate() {
+        let (x, y) = (x.to_f64(), y.to_f64());
+        if !x.is_nan() && !y.is_nan() {
+            observations += 1;
+        }
+        state.update(x, y);
+        if observations >= min_length {
+            output_col[row] = T::from_f64(Stat::get(&state));
+        }
+    }
+}
+
+fn mirror_matrix<T: Float>(output: &mut Array3<T>) {
+    let (num_rows, num_cols) = (output.shape()[0], output.shape()[1]);
+    for left in 0..num_cols {
+        for right in left + 1..num_cols {
+            for row in 0..num_rows {
+                output[[row, right, left]] = output[[row, left, right]];
+            }
+        }
+    }
+}
+
+fn process_weighted_column<T: Float>(
+    input_col: &ArrayView1<T>,
+    output_col: &mut ArrayViewMut1<T>,
+    weights: &[f64],
+    min_length: usize,
+    num_rows: usize,
+    normalize: bool
+) {
+    let length: usize = weights.len();
+
+    for row in 0..num_rows {
+        let start: usize = (row + 1).saturating_sub(length);
+        let window_weights = &weights[length - (row + 1 - start)..];
+        let mut observations: usize = 0;
+        let mut total: f64 = 0.0;
+        let mut total_weight: f64 = 0.0;
+
+        for (&value, &weight) in input_col.slice(s![start..=row]).iter().zip(window_weights) {
+            if !value.is_nan() {
+                observations += 1;
+                total += weight * value.to_f64();
+                total_weight += weight;
+            }
+        }
+
+        if observations >= min_length {
+            output_col[row] = T::from_f64(if normalize { total / total_weight } else { total });
+        }
+    }
+}
+
+fn process_agg_column<T: Float, Stat: calculators::StatCalculator>(
+    input_col: &ArrayView1<T>,
+    output_col: &mut ArrayViewMut1<T>
+) {
+    let mut state = Stat::new();
+    let mut observations: usize = 0;
+
+    for &value in input_col.iter() {
+        if !value.is_nan() {
+            observations += 1;
+            Stat::add_value(&mut state, value);
+        }
+    }
+
+    if observations > 0 {
+        output_col[0] = Stat::get(&state, observations);
+    }
+}
+
+fn process_agg_deque_column<T: Float, Stat: calculators::DequeStatCalculator>(
+    input_col: &ArrayView1<T>
+) -> Option<(T, usize)> {
+    let mut best: Option<(T, usize)> = None;
+
+    for (row, &value) in input_col.iter().enumerate() {
+        if value.is_nan() {
+            continue;
+        }
+        match best {
+            Some((current, _)) if !Stat::is_better(value, current) => {}
+            _ => {
+                best = Some((value, row));
+            }
+        }
+    }
+
+    best
+}
+
+fn process_deque_column<T: Float, Stat: calculators::DequeStatCalculator>(
+    input_col: &ArrayView1<T>,
+    output_col: &mut ArrayViewMut1<T>,
+    length: usize,
+    min_length: usize,
+    num_rows: usize,
+    arg: bool
 ) {
     let mut deque = Stat::new();
     let mut window = calculators::WindowState::new();
 
-    for row in 0..length {
+    for row in 0..length.min(num_rows) {
         window.current = input_col[row];
         if !window.current.is_nan() {
             window.observations += 1;
-            Stat::add_value(&mut deque, window.current, row);
+            if arg {
+                Stat::add_latest_value(&mut deque, window.current, row);
+            } else {
+                Stat::add_value(&mut deque, window.current, row);
+            }
         }
         if window.observations >= min_length {
-            if let Some(&(val, _)) = deque.front() {
-                output_col[row] = val;
+            if let Some(&(val, idx)) = deque.front() {
+                output_col[row] = if arg { T::from_f64((row - idx) as f64) } else { val };
             }
         }
     }
 
     for row in length..num_rows {
-        window.refresh(&input_col, row, length);
-        window.compute_deque_row::<Stat>(&mut deque, row);
+        window.refresh(input_col, row, length);
+        window.compute_deque_row::<Stat>(&mut deque, row, arg);
         if window.observations >= min_length {
-            if let Some(&(val, _)) = deque.front() {
-                output_col[row] = val;
+            if let Some(&(val, idx)) = deque.front() {
+                output_col[row] = if arg { T::from_f64((row - idx) as f64) } else { val };
             }
         }
     }
 }
 
-pub fn process_rank_column(
-    input_col: &ArrayView1<f64>,
-    output_col: &mut ArrayViewMut1<f64>,
+pub fn process_rank_column<T: Float>(
+    input_col: &ArrayView1<T>,
+    output_col: &mut ArrayViewMut1<T>,
     length: usize,
     min_length: usize,
-    num_rows: usize
+    num_rows: usize,
+    offset: usize
 ) {
-    for row in min_length - 1..length {
-        let current: f64 = input_col[row];
-        if current.is_nan() {
+    let mut rank = calculators::Rank::new(input_col);
+
+    for row in 0..num_rows {
+        if row >= length && !input_col[row - length].is_nan() {
+            rank.remove(input_col[row - length]);
+        }
+        if !input_col[row].is_nan() {
+            rank.add(input_col[row]);
+        }
+        if row < offset {
             continue;
         }
 
-        let mut rank_count = calculators::Rank::new();
-        for j in 0..row {
-            let other: f64 = input_col[j];
-            rank_count.add(other, current);
+        let current: T = input_col[row - offset];
+        if !current.is_nan() && rank.valid_count >= min_length {
+            output_col[row] = T::from_f64(rank.get(current));
+        }
+    }
+}
+
+fn process_agg_median_column<T: Float>(
+    input_col: &ArrayView1<T>,
+    output_col: &mut ArrayViewMut1<T>
+) {
+    let mut values: Vec<T> = input_col
+        .iter()
+        .copied()
+        .filter(|value| !value.is_nan())
+        .collect();
+
+    if values.is_empty() {
+        return;
+    }
+
+    let is_odd: bool = values.len() % 2 == 1;
+    let mid: usize = values.len() / 2;
+    let (lower, &mut upper, _) = values.select_nth_unstable_by(mid, T::total_cmp);
+
+    if is_odd {
+        output_col[0] = upper;
+    } else {
+        let lower_max: f64 = lower
+            .iter()
+            .map(|value| value.to_f64())
+            .fold(f64::NEG_INFINITY, f64::max);
+        output_col[0] = T::from_f64((lower_max + upper.to_f64()) / 2.0);
+    }
+}
+
+fn process_agg_rank_column<T: Float>(
+    input_col: &ArrayView1<T>,
+    output_col: &mut ArrayViewMut1<T>
+) {
+    let mut sorted: Vec<(T, usize)> = input_col
+        .iter()
+        .copied()
+        .enumerate()
+        .filter(|(_, value)| !value.is_nan())
+        .map(|(row, value)| (value, row))
+        .collect();
+    sorted.sort_unstable_by(|a, b| a.0.total_cmp(&b.0));
+
+    let observations: f64 = sorted.len() as f64;
+    let mut start: usize = 0;
+
+    while start < sorted.len() {
+        let mut end: usize = start + 1;
+        while end < sorted.len() && sorted[end].0 == sorted[start].0 {
+            end += 1;
+        }
+
+        let rank: T = T::from_f64(stats::rank(2 * start, end - start, observations));
+        for &(_, row) in &sorted[start..end] {
+            output_col[row] = rank;
+        }
+        start = end;
+    }
+}
+
+fn process_quantiles_column<T: Float>(
+    input_col: &ArrayView1<T>,
+    output_cols: &mut [ArrayViewMut1<T>],
+    length: usize,
+    min_length: usize,
+    num_rows: usize,
+    quantiles: &[f64],
+    interpolation: stats::Interpolation
+) {
+    let mut rank = calculators::Rank::new(input_col);
+    let mut window = calculators::WindowState::new();
+
+    for row in 0..length.min(num_rows) {
+        window.current = input_col[row];
+        if !window.current.is_nan() {
+            rank.add(window.current);
         }
 
-        if rank_count.valid_count >= min_length {
-            output_col[row] = rank_count.get();
+        if rank.valid_count >= min_length {
+            for (output_col, &quantile) in output_cols.iter_mut().zip(quantiles) {
+                output_col[row] = T::from_f64(rank.quantile(quantile, interpolation));
+            }
         }
     }
 
     for row in length..num_rows {
-        let current: f64 = input_col[row];
-        if current.is_nan() {
-            continue;
+        window.refresh(input_col, row, length);
+        if !window.current.is_nan() {
+            rank.add(window.current);
         }
 
-        let mut rank_count = calculators::Rank::new();
-        let start_idx: usize = row - length + 1;
-        for j in start_idx..row {
-            let other: f64 = input_col[j];
-            rank_count.add(other, current);
+        if !window.precedent.is_nan() {
+            rank.remove(window.precedent);
         }
 
-        if rank_count.valid_count >= min_length {
-            output_col[row] = rank_count.get();
+        if rank.valid_count >= min_length {
+            for (output_col, &quantile) in output_cols.iter_mut().zip(quantiles) {
+                output_col[row] = T::from_f64(rank.quantile(quantile, interpolation));
+            }
         }
     }
 }
 
-fn process_median_column(
-    input_col: &ArrayView1<f64>,
-    output_col: &mut ArrayViewMut1<f64>,
+fn process_quantile_column<T: Float>(
+    input_col: &ArrayView1<T>,
+    output_col: &mut ArrayViewMut1<T>,
     length: usize,
     min_length: usize,
-    num_rows: usize
+    num_rows: usize,
+    quantile: f64,
+    interpolation: stats::Interpolation
 ) {
-    let mut small_heap = calculators::Indexed::new(length, num_rows, true);
-    let mut large_heap = calculators::Indexed::new(length, num_rows, false);
-    let mut window_q = VecDeque::with_capacity(length + 1);
+    let mut heaps = calculators::Quantile::new(
+        length.min(num_rows),
+        num_rows,
+        quantile,
+        interpolation
+    );
     let mut window = calculators::WindowState::new();
 
-    for row in 0..length {
+    for row in 0..length.min(num_rows) {
         window.current = input_col[row];
+        if !window.current.is_nan() {
+            window.observations += 1;
+            heaps.push(window.current, row);
+        }
 
-        window_q.push_back((window.current, row));
+        if window.observations >= min_length {
+            if let Some(val) = heaps.get() {
+                output_col[row] = T::from_f64(val);
+            }
+        }
+    }
 
+    for row in length..num_rows {
+        window.refresh(input_col, row, length);
         if !window.current.is_nan() {
             window.observations += 1;
+            heaps.push(window.current, row);
+        }
 
-            if let Some((max_small, _)) = small_heap.peek() {
-                if window.current > max_small {
-                    large_heap.push(window.current, row);
-                } else {
-                    small_heap.push(window.current, row);
-                }
-            } else {
-                small_heap.push(window.current, row);
-            }
+        if !window.precedent.is_nan() {
+            window.observations -= 1;
+            heaps.remove(window.precedent_idx);
         }
 
-        while small_heap.heap.len() > large_heap.heap.len() + 1 {
-            if let Some((val, idx)) = small_heap.pop() {
-                large_heap.push(val, idx);
+        if window.observations >= min_length {
+            if let Some(val) = heaps.get() {
+                output_col[row] = T::from_f64(val);
             }
         }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use numpy::ndarray::{ Array1, Array2 };
 
-        while large_heap.heap.len() > small_heap.heap.len() {
-            if let Some((val, idx)) = large_heap.pop() {
-                small_heap.push(val, idx);
+    fn run_deque<Stat: calculators::DequeStatCalculator>(
+        values: &[f64],
+        length: usize,
+        min_length: usize,
+        arg: bool
+    ) -> Vec<f64> {
+        let input = Array1::from(values.to_vec());
+        let mut output = Array1::from_elem(values.len(), f64::NAN);
+        process_deque_column::<f64, Stat>(
+            &input.view(),
+            &mut output.view_mut(),
+            length,
+            min_length,
+            values.len(),
+            arg
+        );
+        output.to_vec()
+    }
+
+    #[test]
+    fn move_argmax_reports_latest_tie() {
+        let values = [1.0, 1.0, 1.0, 3.0, 3.0, 2.0];
+        let positions = run_deque::<calculators::Max>(&values, 3, 1, true);
+        assert_eq!(positions, [0.0, 0.0, 0.0, 0.0, 0.0, 1.0]);
+        let extremes = run_deque::<calculators::Max>(&values, 3, 1, false);
+        assert_eq!(extremes, [1.0, 1.0, 1.0, 3.0, 3.0, 3.0]);
+    }
+
+    #[test]
+    fn move_argmin_reports_latest_tie() {
+        let values = [2.0, 2.0, f64::NAN, 1.0, 1.0, 4.0, 4.0, 4.0];
+        let output = run_deque::<calculators::Min>(&values, 3, 1, true);
+        assert_eq!(output, [0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 2.0, 0.0]);
+    }
+
+    #[test]
+    fn centered_window_is_aligned_on_middle_row() {
+        let input = Array1::from(vec![1.0, 5.0, 2.0, 8.0, 3.0]);
+        let mut output = Array1::from_elem(5, f64::NAN);
+        process_window_column(
+            &input.view(),
+            &mut output.view_mut(),
+            3,
+            5,
+            true,
+            |input_col, output_col, num_rows| {
+                process_deque_column::<f64, calculators::Max>(
+                    input_col,
+                    output_col,
+                    3,
+                    1,
+                    num_rows,
+                    true
+                );
             }
-        }
+        );
+        assert_eq!(output.to_vec(), [0.0, 1.0, 0.0, 1.0, 2.0]);
+    }
 
-        if window.observations >= min_length {
-            if small_heap.heap.len() > large_heap.heap.len() {
-                if let Some((val, _)) = small_heap.peek() {
-                    output_col[row] = val;
+    #[test]
+    fn centered_linreg_reports_residual_of_middle_row() {
+        let x = Array1::from(vec![0.0, 1.0, 2.0, 3.0, 4.0]);
+        let y = Array1::from(vec![0.0, 1.0, 5.0, 3.0, 4.0]);
+        let mut outputs = Array2::from_elem((LINREG_OUTPUTS, 5), f64::NAN);
+        let mut output_cols: Vec<_> = outputs.rows_mut().into_iter().collect();
+        process_window_pair(
+            &x.view(),
+            &y.view(),
+            &mut output_cols,
+            3,
+            5,
+            true,
+            |x_col, y_col, output_cols, padded_rows| {
+                let offset: usize = padded_rows - 5;
+                process_linreg_column(x_col, y_col, output_cols, 3, 2, padded_rows, offset);
+            }
+        );
+        let residuals = outputs.row(3);
+        assert!((residuals[1] + 1.0).abs() < 1e-12);
+        assert!(residuals[4].abs() < 1e-12);
+    }
+
+    #[test]
+    fn agg_argmax_keeps_first_tie() {
+        let input = Array1::from(vec![f64::NAN, 3.0, 1.0, 3.0]);
+        let best = process_agg_deque_column::<f64, calculators::Max>(&input.view());
+        assert_eq!(best, Some((3.0, 1)));
+        let input = Array1::from(vec![f64::NAN, f64::NAN]);
+        assert_eq!(process_agg_deque_column::<f64, calculators::Min>(&input.view()), None);
+    }
+
+    fn run_stat<Stat: calculators::StatCalculator>(
+        values: &[f64],
+        length: usize,
+        min_length: usize
+    ) -> Vec<f64> {
+        let input = Array1::from(values.to_vec());
+        let mut output = Array1::from_elem(values.len(), f64::NAN);
+        process_stat_column::<f64, Stat>(
+            &input.view(),
+            &mut output.view_mut(),
+            length,
+            min_length,
+            values.len()
+        );
+        output.to_vec()
+    }
+
+    fn two_pass_moments(window: &[f64]) -> (f64, f64, f64, f64) {
+        let shift: f64 = window
+            .iter()
+            .copied()
+            .find(|value| !value.is_nan())
+            .unwrap_or(0.0);
+        let valid: Vec<f64> = window
+            .iter()
+            .filter(|value| !value.is_nan())
+            .map(|value| value - shift)
+            .collect();
+        let obs: f64 = valid.len() as f64;
+        let mean: f64 = valid.iter().sum::<f64>() / obs;
+        let moment = |power: i32| {
+            valid
+                .iter()
+                .map(|value| (value - mean).powi(power))
+                .sum::<f64>()
+        };
+        (obs, moment(2), moment(3), moment(4))
+    }
+
+    #[test]
+    fn higher_moments_match_two_pass_reference_at_large_offset() {
+        let length: usize = 20;
+        let values: Vec<f64> = (0..500)
+            .map(|row| {
+                if row % 37 == 5 {
+                    return f64::NAN;
                 }
-            } else if !small_heap.heap.is_empty() {
-                let s_val: f64 = small_heap.peek().unwrap().0;
-                let l_val: f64 = large_heap.peek().unwrap().0;
-                output_col[row] = (s_val + l_val) / 2.0;
+                let noise: f64 = ((row as f64) * 0.7).sin() + 0.3 * ((row as f64) * 1.3).cos();
+                1e9 + noise * if row % 100 < 50 { 1.0 } else { 1e3 }
+            })
+            .collect();
+        let var = run_stat::<calculators::Var>(&values, length, 4);
+        let skew = run_stat::<calculators::Skewness>(&values, length, 4);
+        let kurt = run_stat::<calculators::Kurtosis>(&values, length, 4);
+
+        for row in 0..values.len() {
+            let start: usize = (row + 1).saturating_sub(length);
+            let (obs, m2, m3, m4) = two_pass_moments(&values[start..=row]);
+            if obs < 4.0 {
+                assert!(var[row].is_nan());
+                continue;
             }
+            let expected_var: f64 = stats::var(m2, obs);
+            assert!((var[row] - expected_var).abs() <= 1e-10 * expected_var, "var at {row}");
+            let expected_skew: f64 = stats::skew(m2, m3, obs);
+            let skew_error: f64 = (skew[row] - expected_skew).abs();
+            assert!(skew_error <= 1e-9 * expected_skew.abs(), "skew at {row}");
+            let expected_kurt: f64 = stats::kurtosis(m2, m4, obs);
+            let kurt_error: f64 = (kurt[row] - expected_kurt).abs();
+            assert!(kurt_error <= 1e-9 * expected_kurt.abs(), "kurt at {row}");
         }
     }
 
-    for row in length..num_rows {
-        window.current = input_col[row];
-        window_q.push_back((window.current, row));
+    fn brute_force_rank(window: &[f64], current: f64, min_length: usize) -> f64 {
+        let valid: Vec<f64> = window
+            .iter()
+            .copied()
+            .filter(|value| !value.is_nan())
+            .collect();
+        if current.is_nan() || valid.len() < min_length {
+            return f64::NAN;
+        }
+        let smaller_count: usize = valid
+            .iter()
+            .filter(|&&value| value < current)
+            .count();
+        let equal_count: usize = valid
+            .iter()
+            .filter(|&&value| value == current)
+            .count();
+        stats::rank(2 * smaller_count, equal_count, valid.len() as f64)
+    }
 
-        if !window.current.is_nan() {
-            window.observations += 1;
+    #[test]
+    fn move_rank_matches_brute_force() {
+        let values: Vec<f64> = (0..200)
+            .map(|row| if row % 11 == 3 { f64::NAN } else { ((row * 7) % 13) as f64 })
+            .collect();
+        for (length, offset) in [(1, 0), (5, 0), (8, 0), (5, 2), (8, 3)] {
+            let padded_rows: usize = values.len() + offset;
+            let mut input = Array1::from_elem(padded_rows, f64::NAN);
+            input.slice_mut(s![..values.len()]).assign(&Array1::from(values.clone()));
+            let mut output = Array1::from_elem(padded_rows, f64::NAN);
+            process_rank_column(
+                &input.view(),
+                &mut output.view_mut(),
+                length,
+                1,
+                padded_rows,
+                offset
+            );
 
-            if let Some((max_small, _)) = small_heap.peek() {
-                if window.current > max_small {
-                    large_heap.push(window.current, row);
-                } else {
-                    small_heap.push(window.current, row);
+            for row in offset..padded_rows {
+                let start: usize = (row + 1).saturating_sub(length);
+                let expected: f64 = brute_force_rank(
+                    &input.as_slice().unwrap()[start..=row],
+                    input[row - offset],
+                    1
+                );
+                let actual: f64 = output[row];
+                assert!(
+                    actual == expected || (actual.is_nan() && expected.is_nan()),
+                    "length {length}, offset {offset}, row {row}: {actual} vs {expected}"
+                );
+            }
+        }
+    }
+
+    fn ols_inputs(num_rows: usize) -> (Array1<f64>, Array1<f64>) {
+        let x = Array1::from_iter(
+            (0..num_rows).map(|row| {
+                if row % 13 == 4 { f64::NAN } else { ((row as f64) * 0.9).sin() }
+            })
+        );
+        let y = Array1::from_iter(
+            (0..num_rows).map(|row| {
+                if row % 17 == 9 {
+                    return f64::NAN;
                 }
-            } else {
-                small_heap.push(window.current, row);
+                2.0 + 3.0 * x[row] + 0.5 * ((row as f64) * 2.3).cos()
+            })
+        );
+        (x, y)
+    }
+
+    fn run_ols(y: &Array1<f64>, regressors: &Array2<f64>, length: usize) -> Array2<f64> {
+        let mut output = Array2::from_elem(regressors.raw_dim(), f64::NAN);
+        process_ols_column(
+            &y.view(),
+            &regressors.view(),
+            &mut output.view_mut(),
+            length,
+            3,
+            y.len(),
+            regressors.ncols(),
+            0
+        );
+        output
+    }
+
+    #[test]
+    fn ols_matches_linreg_with_intercept() {
+        let (x, y) = ols_inputs(120);
+        let regressors = Array2::from_shape_fn((x.len(), 2), |(row, col)| {
+            if col == 0 { 1.0 } else { x[row] }
+        });
+        let coefficients = run_ols(&y, &regressors, 10);
+        let mut outputs = Array2::from_elem((LINREG_OUTPUTS, x.len()), f64::NAN);
+        let mut output_cols: Vec<_> = outputs.rows_mut().into_iter().collect();
+        process_linreg_column(&x.view(), &y.view(), &mut output_cols, 10, 3, x.len(), 0);
+
+        for row in 0..x.len() {
+            let (slope, intercept) = (outputs[[0, row]], outputs[[1, row]]);
+            assert_eq!(coefficients[[row, 0]].is_nan(), intercept.is_nan(), "row {row}");
+            if intercept.is_nan() {
+                continue;
             }
+            assert!((coefficients[[row, 0]] - intercept).abs() <= 1e-9, "intercept at {row}");
+            assert!((coefficients[[row, 1]] - slope).abs() <= 1e-9, "slope at {row}");
         }
+    }
 
-        if window_q.len() > length {
-            (window.precedent, window.precedent_idx) = window_q.pop_front().unwrap();
+    #[test]
+    fn ols_ridge_keeps_rank_deficient_windows_finite() {
+        let (x, y) = ols_inputs(60);
+        let regressors = Array2::from_shape_fn((x.len(), 3), |(row, col)| {
+            match col {
+                0 => 1.0,
+                1 => x[row],
+                _ => 2.0 * x[row],
+            }
+        });
+        let coefficients = run_ols(&y, &regressors, 8);
+        let mut outputs = Array2::from_elem((LINREG_OUTPUTS, x.len()), f64::NAN);
+        let mut output_cols: Vec<_> = outputs.rows_mut().into_iter().collect();
+        process_linreg_column(&x.view(), &y.view(), &mut output_cols, 8, 3, x.len(), 0);
 
-            if !window.precedent.is_nan() {
-                window.observations -= 1;
+        for row in 7..x.len() {
+            let row_coefficients = coefficients.row(row);
+            assert!(row_coefficients.iter().all(|value| value.is_finite()), "row {row}");
+            let slope: f64 = row_coefficients[1] + 2.0 * row_coefficients[2];
+            assert!((slope - outputs[[0, row]]).abs() <= 1e-4, "slope at {row}");
+        }
+    }
 
-                if small_heap.remove(window.precedent_idx) {
-                } else {
-                    large_heap.remove(window.precedent_idx);
-                }
+    fn run_ewm<Stat: calculators::EwmStatCalculator>(
+        values: &[f64],
+        alpha: f64,
+        adjust: bool,
+        ignore_na: bool
+    ) -> Vec<f64> {
+        let input = Array1::from(values.to_vec());
+        let mut output = Array1::from_elem(values.len(), f64::NAN);
+        process_ewm_column::<f64, Stat>(
+            &input.view(),
+            &input.view(),
+            &mut output.view_mut(),
+            1,
+            alpha,
+            adjust,
+            ignore_na
+        );
+        output.to_vec()
+    }
+
+    fn assert_close(actual: &[f64], expected: &[f64]) {
+        for (row, (&actual, &expected)) in actual.iter().zip(expected).enumerate() {
+            assert!(
+                (actual - expected).abs() <= 1e-12 || (actual.is_nan() && expected.is_nan()),
+                "row {row}: {actual} vs {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn ewm_matches_pandas_for_every_adjust_and_ignore_na() {
+        let nan: f64 = f64::NAN;
+        let values = [1.0, nan, 3.0, 4.0, nan, 2.0];
+        let cases = [
+            (
+                true,
+                false,
+                [1.0, 1.0, 2.6, 3.4615384615384617, 3.4615384615384617, 2.422222222222222],
+                [nan, nan, 2.0, 1.3636363636363635, 1.3636363636363635, 1.4173913043478261],
+            ),
+            (
+                true,
+                true,
+                [1.0, 1.0, 2.3333333333333335, 3.2857142857142856, 3.2857142857142856, 2.6],
+                [nan, nan, 2.0, 1.8571428571428568, 1.8571428571428568, 1.4571428571428569],
+            ),
+            (
+                false,
+                false,
+                [
+                    1.0,
+                    1.0,
+                    2.3333333333333335,
+                    3.166666666666667,
+                    3.166666666666667,
+                    2.388888888888889,
+                ],
+                [nan, nan, 2.0, 1.8636363636363633, 1.8636363636363633, 1.3313253012048198],
+            ),
+            (
+                false,
+                true,
+                [1.0, 1.0, 2.0, 3.0, 3.0, 2.5],
+                [nan, nan, 2.0, 2.4000000000000004, 2.4000000000000004, 1.5238095238095237],
+            ),
+        ];
+        for (adjust, ignore_na, mean, var) in cases {
+            assert_close(&run_ewm::<calculators::EwmMean>(&values, 0.5, adjust, ignore_na), &mean);
+            assert_close(&run_ewm::<calculators::EwmVar>(&values, 0.5, adjust, ignore_na), &var);
+        }
+    }
+
+    #[test]
+    fn ewm_cov_matrix_diagonal_is_ewm_var() {
+        let columns: Vec<Vec<f64>> = vec![
+            vec![1.0, f64::NAN, 3.0, 4.0, f64::NAN, 2.0, 5.0],
+            vec![2.0, 0.5, f64::NAN, 1.5, 3.0, f64::NAN, 4.0],
+            vec![f64::NAN, 1.0, 1.0, 2.0, 0.0, 7.0, 3.0],
+        ];
+        let num_rows: usize = columns[0].len();
+        let inputs: Vec<Array1<f64>> = columns.iter().cloned().map(Array1::from).collect();
+        let mut output = Array3::from_elem((num_rows, inputs.len(), inputs.len()), f64::NAN);
+        for left in 0..inputs.len() {
+            for right in left..inputs.len() {
+                process_ewm_column::<f64, calculators::EwmCov>(
+                    &inputs[left].view(),
+                    &inputs[right].view(),
+                    &mut output.slice_mut(s![.., left, right]),
+                    1,
+                    0.3,
+                    true,
+                    false
+                );
             }
         }
+        mirror_matrix(&mut output);
 
-        while small_heap.heap.len() > large_heap.heap.len() + 1 {
-            if let Some((val, idx)) = small_heap.pop() {
-                large_heap.push(val, idx);
+        for (col, values) in columns.iter().enumerate() {
+            let var = run_ewm::<calculators::EwmVar>(values, 0.3, true, false);
+            assert_close(&output.slice(s![.., col, col]).to_vec(), &var);
+            for other in 0..columns.len() {
+                let upper: Vec<f64> = output.slice(s![.., col, other]).to_vec();
+                assert_close(&upper, &output.slice(s![.., other, col]).to_vec());
+            }
+            let corr = run_ewm::<calculators::EwmCorr>(values, 0.3, true, false);
+            for (row, &value) in corr.iter().enumerate() {
+                assert!(value.is_nan() || (value - 1.0).abs() <= 1e-12, "column {col}, row {row}");
             }
+            assert!(corr.iter().any(|value| !value.is_nan()));
         }
+    }
 
-        while large_heap.heap.len() > small_heap.heap.len() {
-            if let Some((val, idx)) = large_heap.pop() {
-                small_heap.push(val, idx);
+    fn brute_force_quantile(
+        window: &[f64],
+        quantile: f64,
+        interpolation: stats::Interpolation
+    ) -> f64 {
+        let mut valid: Vec<f64> = window
+            .iter()
+            .copied()
+            .filter(|value| !value.is_nan())
+            .collect();
+        if valid.is_empty() {
+            return f64::NAN;
+        }
+        valid.sort_by(f64::total_cmp);
+        let (lower_idx, fraction) = stats::quantile_position(quantile, valid.len());
+        let upper: f64 = valid[(lower_idx + 1).min(valid.len() - 1)];
+        stats::interpolate(valid[lower_idx], upper, lower_idx, fraction, interpolation)
+    }
+
+    const QUANTILES: [f64; 7] = [0.0, 0.1, 0.25, 0.5, 0.75, 0.9, 1.0];
+    const INTERPOLATIONS: [stats::Interpolation; 5] = [
+        stats::Interpolation::Linear,
+        stats::Interpolation::Lower,
+        stats::Interpolation::Higher,
+        stats::Interpolation::Nearest,
+        stats::Interpolation::Midpoint,
+    ];
+
+    fn quantile_inputs() -> Vec<f64> {
+        (0..150)
+            .map(|row| if row % 9 == 2 { f64::NAN } else { ((row * 5) % 11) as f64 * 0.5 })
+            .collect()
+    }
+
+    fn assert_sorted_window_quantile(
+        values: &[f64],
+        output: &ArrayView1<f64>,
+        length: usize,
+        quantile: f64,
+        interpolation: stats::Interpolation
+    ) {
+        for row in 0..values.len() {
+            let start: usize = (row + 1).saturating_sub(length);
+            let expected: f64 = brute_force_quantile(&values[start..=row], quantile, interpolation);
+            let actual: f64 = output[row];
+            assert!(
+                (actual - expected).abs() <= 1e-12 || (actual.is_nan() && expected.is_nan()),
+                "length {length}, q {quantile}, row {row}: {actual} vs {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn move_quantile_matches_sorted_window() {
+        let values = quantile_inputs();
+        let input = Array1::from(values.clone());
+        for length in [1, 4, 7, 20] {
+            for interpolation in INTERPOLATIONS {
+                for quantile in QUANTILES {
+                    let mut output = Array1::from_elem(values.len(), f64::NAN);
+                    process_quantile_column(
+                        &input.view(),
+                        &mut output.view_mut(),
+                        length,
+                        1,
+                        values.len(),
+                        quantile,
+                        interpolation
+                    );
+                    assert_sorted_window_quantile(
+                        &values,
+                        &output.view(),
+                        length,
+                        quantile,
+                        interpolation
+                    );
+                }
             }
         }
+    }
 
-        if window.observations >= min_length {
-            if small_heap.heap.len() > large_heap.heap.len() {
-                if let Some((val, _)) = small_heap.peek() {
-                    output_col[row] = val;
+    #[test]
+    fn move_quantiles_match_sorted_window() {
+        let values = quantile_inputs();
+        let input = Array1::from(values.clone());
+        for length in [1, 4, 7, 20] {
+            for interpolation in INTERPOLATIONS {
+                let mut outputs = Array2::from_elem((QUANTILES.len(), values.len()), f64::NAN);
+                let mut output_cols: Vec<_> = outputs.rows_mut().into_iter().collect();
+                process_quantiles_column(
+                    &input.view(),
+                    &mut output_cols,
+                    length,
+                    1,
+                    values.len(),
+                    &QUANTILES,
+                    interpolation
+                );
+                for (q, output) in QUANTILES.into_iter().zip(outputs.rows()) {
+                    assert_sorted_window_quantile(&values, &output, length, q, interpolation);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn move_wma_matches_linear_weights() {
+        let length: usize = 6;
+        let values: Vec<f64> = (0..300)
+            .map(|row| {
+                match row {
+                    _ if row % 7 == 3 => f64::NAN,
+                    151 => 1e12,
+                    _ => ((row as f64) * 0.37).sin() * 10.0,
+                }
+            })
+            .collect();
+        let output = run_stat::<calculators::Wma>(&values, length, 2);
+
+        for row in 0..values.len() {
+            let (mut weighted_sum, mut total_weight, mut count) = (0.0, 0.0, 0);
+            for age in 0..length.min(row + 1) {
+                let value: f64 = values[row - age];
+                if !value.is_nan() {
+                    let weight: f64 = (length - age) as f64;
+                    weighted_sum += weight * value;
+                    total_weight += weight;
+                    count += 1;
                 }
-            } else if !small_heap.heap.is_empty() {
-                let s_val: f64 = small_heap.peek().unwrap().0;
-                let l_val: f64 = large_heap.peek().unwrap().0;
-                output_col[row] = (s_val + l_val) / 2.0;
             }
+            if count < 2 {
+                assert!(output[row].is_nan(), "row {row}");
+                continue;
+            }
+            let expected: f64 = weighted_sum / total_weight;
+            let tolerance: f64 = 1e-10 * expected.abs().max(1.0);
+            assert!((output[row] - expected).abs() <= tolerance, "row {row}");
         }
     }
+
+    #[test]
+    fn weighted_options_must_match_weights() {
+        assert!(validation::check_explicit_weights(None, None).is_ok());
+        assert!(validation::check_explicit_weights(Some(10), None).is_err());
+        assert!(validation::check_explicit_weights(None, Some(1.0)).is_err());
+        assert!(validation::get_named_weights("hann", Some(5), Some(1.0)).is_err());
+        assert!(validation::get_named_weights("hann", None, None).is_err());
+        let weights = validation::get_named_weights("gaussian", Some(5), Some(1.0)).unwrap();
+        assert_eq!(weights.len(), 5);
+    }
 }
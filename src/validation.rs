@@ -0,0 +1,213 @@
+use numpy::ndarray::Axis;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use crate::stats::{ self, Interpolation, WindowType };
+
+pub fn get_axis(ndim: usize, axis: isize) -> PyResult<Axis> {
+    let normalized: isize = if axis < 0 { axis + (ndim as isize) } else { axis };
+    if normalized < 0 || normalized >= (ndim as isize) {
+        return Err(
+            PyValueError::new_err(
+                format!("axis {axis} is out of bounds for array of dimension {ndim}")
+            )
+        );
+    }
+    Ok(Axis(normalized as usize))
+}
+
+pub fn check_window(length: usize, min_length: usize) -> PyResult<()> {
+    if length == 0 {
+        return Err(PyValueError::new_err("length must be at least 1"));
+    }
+    check_min_length(min_length)?;
+    if min_length > length {
+        return Err(
+            PyValueError::new_err(
+                format!("min_length ({min_length}) must not exceed length ({length})")
+            )
+        );
+    }
+    Ok(())
+}
+
+pub fn check_min_length(min_length: usize) -> PyResult<()> {
+    if min_length == 0 {
+        return Err(PyValueError::new_err("min_length must be at least 1"));
+    }
+    Ok(())
+}
+
+pub fn get_alpha(
+    com: Option<f64>,
+    span: Option<f64>,
+    halflife: Option<f64>,
+    alpha: Option<f64>
+) -> PyResult<f64> {
+    match (com, span, halflife, alpha) {
+        (Some(com), None, None, None) => {
+            if com < 0.0 {
+                return Err(PyValueError::new_err(format!("com ({com}) must be at least 0")));
+            }
+            Ok(1.0 / (1.0 + com))
+        }
+        (None, Some(span), None, None) => {
+            if span < 1.0 {
+                return Err(PyValueError::new_err(format!("span ({span}) must be at least 1")));
+            }
+            Ok(2.0 / (span + 1.0))
+        }
+        (None, None, Some(halflife), None) => {
+            if halflife <= 0.0 {
+                return Err(
+                    PyValueError::new_err(format!("halflife ({halflife}) must be positive"))
+                );
+            }
+            Ok(1.0 - (-std::f64::consts::LN_2 / halflife).exp())
+        }
+        (None, None, None, Some(alpha)) => {
+            if !(alpha > 0.0 && alpha <= 1.0) {
+                return Err(
+                    PyValueError::new_err(format!("alpha ({alpha}) must be in the (0, 1] range"))
+                );
+            }
+            Ok(alpha)
+        }
+        _ =>
+            Err(
+                PyValueError::new_err(
+                    "exactly one of com, span, halflife or alpha must be provided"
+                )
+            ),
+    }
+}
+
+pub fn check_same_shape(left: &[usize], right: &[usize]) -> PyResult<()> {
+    if left != right {
+        return Err(
+            PyValueError::new_err(
+                format!("arrays must have the same shape, got {left:?} and {right:?}")
+            )
+        );
+    }
+    Ok(())
+}
+
+pub fn check_target(shape: &[usize], num_rows: usize) -> PyResult<()> {
+    if shape.len() != 1 || shape[0] != num_rows {
+        return Err(
+            PyValueError::new_err(
+                format!("target must be 1-D with {num_rows} rows along axis, got shape {shape:?}")
+            )
+        );
+    }
+    Ok(())
+}
+
+pub fn check_matrix(ndim: usize) -> PyResult<()> {
+    if ndim != 2 {
+        return Err(PyValueError::new_err(format!("array must be 2-D, got {ndim} dimensions")));
+    }
+    Ok(())
+}
+
+pub fn check_regressors(y_shape: &[usize], x_shape: &[usize]) -> PyResult<()> {
+    if y_shape.len() != 2 || x_shape.len() != 3 || x_shape[..2] != y_shape[..] || x_shape[2] == 0 {
+        return Err(
+            PyValueError::new_err(
+                format!(
+                    "y must be 2-D and x 3-D with the same first two dimensions and at least one \
+                    regressor, got shapes {y_shape:?} and {x_shape:?}"
+                )
+            )
+        );
+    }
+    Ok(())
+}
+
+pub fn check_quantile(quantile: f64) -> PyResult<()> {
+    if !(0.0..=1.0).contains(&quantile) {
+        return Err(PyValueError::new_err(format!("q ({quantile}) must be between 0 and 1")));
+    }
+    Ok(())
+}
+
+pub fn check_quantiles(quantiles: &[f64]) -> PyResult<()> {
+    if quantiles.is_empty() {
+        return Err(PyValueError::new_err("qs must contain at least one quantile"));
+    }
+    quantiles.iter().try_for_each(|&quantile| check_quantile(quantile))
+}
+
+pub fn get_interpolation(interpolation: &str) -> PyResult<Interpolation> {
+    match interpolation {
+        "linear" => Ok(Interpolation::Linear),
+        "lower" => Ok(Interpolation::Lower),
+        "higher" => Ok(Interpolation::Higher),
+        "nearest" => Ok(Interpolation::Nearest),
+        "midpoint" => Ok(Interpolation::Midpoint),
+        _ =>
+            Err(
+                PyValueError::new_err(
+                    format!(
+                        "interpolation must be one of 'linear', 'lower', 'higher', 'nearest' \
+                        or 'midpoint', got '{interpolation}'"
+                    )
+                )
+            ),
+    }
+}
+
+pub fn check_weights(weights: &[f64]) -> PyResult<()> {
+    if weights.is_empty() {
+        return Err(PyValueError::new_err("weights must contain at least one value"));
+    }
+    if weights.iter().any(|weight| !weight.is_finite()) {
+        return Err(PyValueError::new_err("weights must all be finite"));
+    }
+    Ok(())
+}
+
+pub fn get_window_type(win_type: &str, std: Option<f64>) -> PyResult<WindowType> {
+    match (win_type, std) {
+        ("boxcar", _) => Ok(WindowType::Boxcar),
+        ("triang", _) => Ok(WindowType::Triang),
+        ("bartlett", _) => Ok(WindowType::Bartlett),
+        ("hann", _) => Ok(WindowType::Hann),
+        ("hamming", _) => Ok(WindowType::Hamming),
+        ("blackman", _) => Ok(WindowType::Blackman),
+        ("gaussian", Some(std)) if std > 0.0 => Ok(WindowType::Gaussian(std)),
+        ("gaussian", _) => Err(PyValueError::new_err("gaussian windows need a positive std")),
+        _ =>
+            Err(
+                PyValueError::new_err(
+                    format!(
+                        "win_type must be one of 'boxcar', 'triang', 'bartlett', 'hann', \
+                        'hamming', 'blackman' or 'gaussian', got '{win_type}'"
+                    )
+                )
+            ),
+    }
+}
+
+pub fn get_named_weights(
+    win_type: &str,
+    length: Option<usize>,
+    std: Option<f64>
+) -> PyResult<Vec<f64>> {
+    let Some(length) = length else {
+        return Err(PyValueError::new_err("named windows need a length"));
+    };
+    check_window(length, 1)?;
+    let window_type = get_window_type(win_type, std)?;
+    if std.is_some() && !matches!(window_type, WindowType::Gaussian(_)) {
+        return Err(PyValueError::new_err("std only applies to gaussian windows"));
+    }
+    Ok(stats::window_weights(window_type, length))
+}
+
+pub fn check_explicit_weights(length: Option<usize>, std: Option<f64>) -> PyResult<()> {
+    if length.is_some() || std.is_some() {
+        return Err(PyValueError::new_err("length and std only apply to named windows"));
+    }
+    Ok(())
+}
@@ -60,14 +60,18 @@ class RSingleFunc(StatFunc[NDArray[np.float64]]):
     library = Library.RUSTATS
 
     def __call__(self, config: BenchmarkConfig) -> NDArray[np.float64]:
-        return self.func(config.array, config.length, config.min_length, False)
+        return self.func(
+            config.array, config.length, config.min_length, False, config.axis
+        )
 
 
 class RParallelFunc(StatFunc[NDArray[np.float64]]):
     library = Library.RUSTATS_PARALLEL
 
     def __call__(self, config: BenchmarkConfig) -> NDArray[np.float64]:
-        return self.func(config.array, config.length, config.min_length, True)
+        return self.func(
+            config.array, config.length, config.min_length, True, config.axis
+        )
 
 
 def move_mean(df: pl.DataFrame, length: int, min_length: int) -> pl.DataFrame: